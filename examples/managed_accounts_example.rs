@@ -0,0 +1,27 @@
+use telnyx_rs::{TelnyxClient, models::CreateManagedAccountRequest};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let request = CreateManagedAccountRequest::builder()
+        .business_name("Acme Inc")
+        .rollup_billing(true) // Optional
+        .build();
+
+    let account_created = client.managed_accounts().create(request).await?;
+    println!("Managed account created: {}", account_created.data.id);
+
+    let account_found = client
+        .managed_accounts()
+        .get(&account_created.data.id)
+        .await?;
+    println!("Managed account found: {}", account_found.data.email);
+
+    let sub_account = client.for_managed_account(&account_found.data)?;
+    let addresses = sub_account.addresses().list(None).await?;
+    println!("Managed account addresses: {}", addresses.data.len());
+
+    Ok(())
+}
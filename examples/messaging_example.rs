@@ -0,0 +1,24 @@
+use telnyx_rs::{TelnyxClient, models::SendMessageRequest};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let request = SendMessageRequest::builder()
+        .from("+13125550000")
+        .to("+13125550001")
+        .text("Your order has shipped")
+        .webhook_url("https://example.com/webhooks/messages") // Optional
+        .build();
+
+    let message_sent = client.messages().send(request).await?;
+    println!("Message sent: {}", message_sent.data.id);
+
+    let message_found = client.messages().get(&message_sent.data.id).await?;
+    for recipient in &message_found.data.to {
+        println!("{}: {:?}", recipient.phone_number, recipient.status);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use telnyx_rs::{TelnyxClient, models::CreateMessagingProfileRequest};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let request = CreateMessagingProfileRequest::builder()
+        .name("Customer notifications")
+        .whitelisted_destinations(vec!["US".into(), "CA".into()])
+        .webhook_url("https://example.com/webhooks/messaging") // Optional
+        .build();
+
+    let profile_created = client.messaging_profiles().create(request).await?;
+    println!("Messaging profile created: {}", profile_created.data.id);
+
+    let profile_found = client
+        .messaging_profiles()
+        .get(&profile_created.data.id)
+        .await?;
+    println!("Messaging profile found: {}", profile_found.data.name);
+
+    client
+        .messaging_profiles()
+        .delete(&profile_found.data.id)
+        .await?;
+    println!("Messaging profile deleted: {}", profile_found.data.id);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use telnyx_rs::{
+    TelnyxClient,
+    models::{
+        CreateNumberOrderRequest, PhoneNumberFeature, PhoneNumberOrderRequest,
+        PhoneNumberSearchFilter,
+    },
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let filter = PhoneNumberSearchFilter::builder()
+        .country_code("US")
+        .locality("Chicago") // Optional
+        .features(vec![PhoneNumberFeature::Sms, PhoneNumberFeature::Voice]) // Optional
+        .limit(1)
+        .build();
+
+    let available = client
+        .available_phone_numbers()
+        .search(Some(filter))
+        .await?;
+    let Some(number) = available.data.first() else {
+        println!("No available phone numbers found");
+        return Ok(());
+    };
+    println!("Available phone number found: {}", number.phone_number);
+
+    let request = CreateNumberOrderRequest::builder()
+        .phone_numbers(vec![
+            PhoneNumberOrderRequest::builder()
+                .phone_number(number.phone_number.clone())
+                .build(),
+        ])
+        .customer_reference("MY REF 001") // Optional
+        .build();
+
+    let order_created = client.number_orders().create(request).await?;
+    println!("Number order created: {}", order_created.data.id);
+
+    let order_found = client.number_orders().get(&order_created.data.id).await?;
+    println!("Number order status: {:?}", order_found.data.status);
+
+    Ok(())
+}
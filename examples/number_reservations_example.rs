@@ -0,0 +1,38 @@
+use telnyx_rs::{
+    TelnyxClient,
+    models::{CreateNumberReservationRequest, PhoneNumberReservationRequest},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let request = CreateNumberReservationRequest::builder()
+        .phone_numbers(vec![
+            PhoneNumberReservationRequest::builder()
+                .phone_number("+13125550000")
+                .build(),
+        ])
+        .customer_reference("MY REF 001") // Optional
+        .build();
+
+    let reservation_created = client.number_reservations().create(request).await?;
+    println!(
+        "Number reservation created: {}",
+        reservation_created.data.id
+    );
+
+    let reservation_extended = client
+        .number_reservations()
+        .extend(&reservation_created.data.id)
+        .await?;
+    for number in &reservation_extended.data.phone_numbers {
+        println!(
+            "{} reserved until {:?}",
+            number.phone_number, number.expired_at
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use telnyx_rs::{
+    TelnyxClient,
+    models::{ListPhoneNumbersParams, PhoneNumberStatus, UpdatePhoneNumberRequest},
+};
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let api_key = std::env::var("TELNYX_API_KEY")?;
+    let client = TelnyxClient::builder().api_key(api_key).build()?;
+
+    let params = ListPhoneNumbersParams::builder()
+        .status(PhoneNumberStatus::Active)
+        .page_size(20) // Optional
+        .build();
+
+    let numbers_found = client.phone_numbers().list(Some(params)).await?;
+    println!("Phone numbers found: {}", numbers_found.data.len());
+
+    if let Some(number) = numbers_found.data.first() {
+        let request = UpdatePhoneNumberRequest::builder()
+            .tags(vec!["support".into()])
+            .customer_reference("MY REF 001") // Optional
+            .build();
+
+        let number_updated = client.phone_numbers().update(&number.id, request).await?;
+        println!(
+            "Phone number updated: {} {:?}",
+            number_updated.data.phone_number, number_updated.data.tags
+        );
+    }
+
+    Ok(())
+}
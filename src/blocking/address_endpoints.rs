@@ -0,0 +1,85 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        Address, AddressAcceptSuggestionRequest, AddressAcceptSuggestionResult, ApiListResponse,
+        ApiResponse, CreateAddressRequest, ListAddressesParams, ValidateAddressRequest,
+        ValidateAddressResult,
+    },
+    request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// Blocking API client for addresses
+///
+/// Blocking counterpart of [`endpoints::AddressApi`].
+#[derive(Debug, Clone)]
+pub struct AddressApi {
+    api: endpoints::AddressApi,
+    runtime: Arc<Runtime>,
+}
+
+impl AddressApi {
+    pub(crate) fn new(api: endpoints::AddressApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List all addresses
+    pub fn list(
+        &self,
+        params: Option<ListAddressesParams>,
+    ) -> Result<ApiListResponse<Address>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all addresses, fetching pages on demand
+    pub fn list_all(&self, params: Option<ListAddressesParams>) -> Paginator<Address> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get an address by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<Address>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Create a new address
+    pub fn create(
+        &self,
+        request: CreateAddressRequest,
+    ) -> Result<ApiResponse<Address>, TelnyxError> {
+        self.runtime.block_on(self.api.create(request))
+    }
+
+    /// Delete an address
+    pub fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.runtime.block_on(self.api.delete(id))
+    }
+
+    /// Validate an address for emergency services
+    pub fn validate(
+        &self,
+        request: ValidateAddressRequest,
+    ) -> Result<ApiResponse<ValidateAddressResult>, TelnyxError> {
+        self.runtime.block_on(self.api.validate(request))
+    }
+
+    /// Accept address suggestions for an address
+    pub fn accept_suggestions(
+        &self,
+        id: &str,
+        request: AddressAcceptSuggestionRequest,
+    ) -> Result<ApiResponse<AddressAcceptSuggestionResult>, TelnyxError> {
+        self.runtime
+            .block_on(self.api.accept_suggestions(id, request))
+    }
+}
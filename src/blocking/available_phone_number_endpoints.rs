@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{AvailablePhoneNumbersResponse, PhoneNumberSearchFilter},
+    request_options::RequestOptions,
+};
+
+/// Blocking API client for searching phone numbers available to order
+///
+/// Blocking counterpart of [`endpoints::AvailablePhoneNumberApi`].
+#[derive(Debug, Clone)]
+pub struct AvailablePhoneNumberApi {
+    api: endpoints::AvailablePhoneNumberApi,
+    runtime: Arc<Runtime>,
+}
+
+impl AvailablePhoneNumberApi {
+    pub(crate) fn new(api: endpoints::AvailablePhoneNumberApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// Search for phone numbers available to order
+    pub fn search(
+        &self,
+        filter: Option<PhoneNumberSearchFilter>,
+    ) -> Result<AvailablePhoneNumbersResponse, TelnyxError> {
+        self.runtime.block_on(self.api.search(filter))
+    }
+}
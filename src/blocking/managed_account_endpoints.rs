@@ -0,0 +1,93 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        AllocatableGlobalOutboundChannels, ApiListResponse, ApiResponse,
+        CreateManagedAccountRequest, EnableManagedAccountRequest, ListManagedAccountsParams,
+        ManagedAccount, UpdateManagedAccountRequest,
+    },
+    request_options::RequestOptions,
+};
+
+/// Blocking API client for managed accounts
+///
+/// Blocking counterpart of [`endpoints::ManagedAccountApi`].
+#[derive(Debug, Clone)]
+pub struct ManagedAccountApi {
+    api: endpoints::ManagedAccountApi,
+    runtime: Arc<Runtime>,
+}
+
+impl ManagedAccountApi {
+    pub(crate) fn new(api: endpoints::ManagedAccountApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List managed accounts
+    pub fn list(
+        &self,
+        params: Option<ListManagedAccountsParams>,
+    ) -> Result<ApiListResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all managed accounts, fetching pages on demand
+    pub fn list_all(&self, params: Option<ListManagedAccountsParams>) -> Paginator<ManagedAccount> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get a managed account by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Create a new managed account
+    pub fn create(
+        &self,
+        request: CreateManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.create(request))
+    }
+
+    /// Update a managed account
+    pub fn update(
+        &self,
+        id: &str,
+        request: UpdateManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.update(id, request))
+    }
+
+    /// Enable a disabled managed account
+    pub fn enable(
+        &self,
+        id: &str,
+        request: EnableManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.enable(id, request))
+    }
+
+    /// Disable a managed account, along with its connections
+    pub fn disable(&self, id: &str) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.runtime.block_on(self.api.disable(id))
+    }
+
+    /// Get the global outbound channels that can still be allocated to
+    /// managed accounts
+    pub fn allocatable_global_outbound_channels(
+        &self,
+    ) -> Result<ApiResponse<AllocatableGlobalOutboundChannels>, TelnyxError> {
+        self.runtime
+            .block_on(self.api.allocatable_global_outbound_channels())
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        ApiResponse, Message, SendGroupMmsRequest, SendMessageRequest,
+        SendNumberPoolMessageRequest, SendShortCodeMessageRequest,
+    },
+    request_options::RequestOptions,
+};
+
+/// Blocking API client for sending and retrieving SMS and MMS messages
+///
+/// Blocking counterpart of [`endpoints::MessagingApi`].
+#[derive(Debug, Clone)]
+pub struct MessagingApi {
+    api: endpoints::MessagingApi,
+    runtime: Arc<Runtime>,
+}
+
+impl MessagingApi {
+    pub(crate) fn new(api: endpoints::MessagingApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// Send an SMS or MMS message
+    pub fn send(&self, request: SendMessageRequest) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.runtime.block_on(self.api.send(request))
+    }
+
+    /// Send a message from a number in a messaging profile's number pool
+    pub fn send_with_number_pool(
+        &self,
+        request: SendNumberPoolMessageRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.runtime
+            .block_on(self.api.send_with_number_pool(request))
+    }
+
+    /// Send a message from a short code
+    pub fn send_short_code(
+        &self,
+        request: SendShortCodeMessageRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.runtime.block_on(self.api.send_short_code(request))
+    }
+
+    /// Send an MMS to a group of recipients
+    pub fn send_group_mms(
+        &self,
+        request: SendGroupMmsRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.runtime.block_on(self.api.send_group_mms(request))
+    }
+
+    /// Get a message by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+}
@@ -0,0 +1,107 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateMessagingProfileRequest, ListMessagingProfilesParams,
+        MessagingPhoneNumber, MessagingProfile, MessagingProfileMetrics, MetricsTimeFrame,
+        PageParams, ShortCode, UpdateMessagingProfileRequest,
+    },
+    request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// Blocking API client for messaging profiles
+///
+/// Blocking counterpart of [`endpoints::MessagingProfileApi`].
+#[derive(Debug, Clone)]
+pub struct MessagingProfileApi {
+    api: endpoints::MessagingProfileApi,
+    runtime: Arc<Runtime>,
+}
+
+impl MessagingProfileApi {
+    pub(crate) fn new(api: endpoints::MessagingProfileApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List messaging profiles
+    pub fn list(
+        &self,
+        params: Option<ListMessagingProfilesParams>,
+    ) -> Result<ApiListResponse<MessagingProfile>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all messaging profiles, fetching pages on demand
+    pub fn list_all(
+        &self,
+        params: Option<ListMessagingProfilesParams>,
+    ) -> Paginator<MessagingProfile> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get a messaging profile by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Create a new messaging profile
+    pub fn create(
+        &self,
+        request: CreateMessagingProfileRequest,
+    ) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.runtime.block_on(self.api.create(request))
+    }
+
+    /// Update a messaging profile
+    pub fn update(
+        &self,
+        id: &str,
+        request: UpdateMessagingProfileRequest,
+    ) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.runtime.block_on(self.api.update(id, request))
+    }
+
+    /// Delete a messaging profile
+    pub fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.runtime.block_on(self.api.delete(id))
+    }
+
+    /// List the phone numbers assigned to a messaging profile
+    pub fn list_phone_numbers(
+        &self,
+        id: &str,
+        params: Option<PageParams>,
+    ) -> Result<ApiListResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.runtime
+            .block_on(self.api.list_phone_numbers(id, params))
+    }
+
+    /// List the short codes assigned to a messaging profile
+    pub fn list_short_codes(
+        &self,
+        id: &str,
+        params: Option<PageParams>,
+    ) -> Result<ApiListResponse<ShortCode>, TelnyxError> {
+        self.runtime.block_on(self.api.list_short_codes(id, params))
+    }
+
+    /// Get message metrics for a messaging profile
+    pub fn metrics(
+        &self,
+        id: &str,
+        time_frame: Option<MetricsTimeFrame>,
+    ) -> Result<ApiResponse<MessagingProfileMetrics>, TelnyxError> {
+        self.runtime.block_on(self.api.metrics(id, time_frame))
+    }
+}
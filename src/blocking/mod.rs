@@ -0,0 +1,171 @@
+//! Blocking (synchronous) client
+//!
+//! Mirrors the async [`TelnyxClient`](crate::TelnyxClient) and its endpoints,
+//! running each call to completion on a runtime owned by the client. The
+//! models and [`TelnyxError`] are shared with the async API.
+//!
+//! The blocking client must not be used from within an async runtime, since
+//! it would block the runtime's threads. For the same reason, the last clone
+//! of a client or of one of its handles must not be dropped inside an async
+//! context: dropping the runtime there panics.
+//!
+//! Clones of a client and its handles share one runtime. Calls made from
+//! several threads at once run concurrently.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use telnyx_rs::blocking::TelnyxClient;
+//!
+//! # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+//! let client = TelnyxClient::builder()
+//!     .api_key("your-api-key")
+//!     .build_blocking()?;
+//!
+//! let address = client.addresses().get("1234567890")?;
+//! # Ok(())
+//! # }
+//! ```
+
+mod address_endpoints;
+mod available_phone_number_endpoints;
+mod managed_account_endpoints;
+mod messaging_endpoints;
+mod messaging_profile_endpoints;
+mod number_order_endpoints;
+mod number_reservation_endpoints;
+mod pagination;
+mod phone_number_endpoints;
+mod request;
+
+pub use address_endpoints::*;
+pub use available_phone_number_endpoints::*;
+pub use managed_account_endpoints::*;
+pub use messaging_endpoints::*;
+pub use messaging_profile_endpoints::*;
+pub use number_order_endpoints::*;
+pub use number_reservation_endpoints::*;
+pub use pagination::*;
+pub use phone_number_endpoints::*;
+pub use request::*;
+
+use std::sync::Arc;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::{Method, client::TelnyxClientBuilder, error::TelnyxError, models::ManagedAccount};
+
+/// The blocking API client for interacting with the Telnyx API
+///
+/// Use [`TelnyxClient::builder()`] and
+/// [`TelnyxClientBuilder::build_blocking()`] to construct a new client, or
+/// wrap an existing async client with [`TelnyxClient::new()`].
+#[derive(Debug, Clone)]
+pub struct TelnyxClient {
+    client: crate::TelnyxClient,
+    runtime: Arc<Runtime>,
+}
+
+impl TelnyxClient {
+    /// Create a new client builder
+    pub fn builder() -> TelnyxClientBuilder {
+        TelnyxClientBuilder::default()
+    }
+
+    /// Wrap an async client
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime fails to start.
+    pub fn new(client: crate::TelnyxClient) -> Result<Self, TelnyxError> {
+        // Each call is driven on its calling thread, so a single worker for
+        // I/O and timers is enough for calls from any number of threads.
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .map_err(|e| TelnyxError::Config(format!("Failed to start runtime: {e}")))?;
+
+        Ok(Self {
+            client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// The async client this client wraps
+    pub fn as_async(&self) -> &crate::TelnyxClient {
+        &self.client
+    }
+
+    /// The addresses API
+    pub fn addresses(&self) -> AddressApi {
+        AddressApi::new(self.client.addresses(), self.runtime.clone())
+    }
+
+    /// Build a request to an API path that has no typed endpoint yet
+    ///
+    /// See [`crate::TelnyxClient::request()`].
+    pub fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        RequestBuilder::new(self.client.request(method, path), self.runtime.clone())
+    }
+
+    /// The managed accounts API
+    pub fn managed_accounts(&self) -> ManagedAccountApi {
+        ManagedAccountApi::new(self.client.managed_accounts(), self.runtime.clone())
+    }
+
+    /// The messaging API
+    pub fn messages(&self) -> MessagingApi {
+        MessagingApi::new(self.client.messages(), self.runtime.clone())
+    }
+
+    /// The messaging profiles API
+    pub fn messaging_profiles(&self) -> MessagingProfileApi {
+        MessagingProfileApi::new(self.client.messaging_profiles(), self.runtime.clone())
+    }
+
+    /// The phone numbers API
+    pub fn phone_numbers(&self) -> PhoneNumberApi {
+        PhoneNumberApi::new(self.client.phone_numbers(), self.runtime.clone())
+    }
+
+    /// The available phone numbers API
+    pub fn available_phone_numbers(&self) -> AvailablePhoneNumberApi {
+        AvailablePhoneNumberApi::new(self.client.available_phone_numbers(), self.runtime.clone())
+    }
+
+    /// The number orders API
+    pub fn number_orders(&self) -> NumberOrderApi {
+        NumberOrderApi::new(self.client.number_orders(), self.runtime.clone())
+    }
+
+    /// The number reservations API
+    pub fn number_reservations(&self) -> NumberReservationApi {
+        NumberReservationApi::new(self.client.number_reservations(), self.runtime.clone())
+    }
+
+    /// A client that sends every request as a managed account
+    ///
+    /// See [`crate::TelnyxClient::for_managed_account()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TelnyxError::Config`] if the account has no API key.
+    pub fn for_managed_account(&self, account: &ManagedAccount) -> Result<Self, TelnyxError> {
+        Ok(Self {
+            client: self.client.for_managed_account(account)?,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
+
+impl TelnyxClientBuilder {
+    /// Build a [`blocking::TelnyxClient`](TelnyxClient)
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`build()`](Self::build), or an error if
+    /// the runtime fails to start.
+    pub fn build_blocking(self) -> Result<TelnyxClient, TelnyxError> {
+        TelnyxClient::new(self.build()?)
+    }
+}
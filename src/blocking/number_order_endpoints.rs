@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateNumberOrderRequest, ListNumberOrdersParams,
+        NumberOrder, SubNumberOrder, UpdateNumberOrderRequest,
+    },
+    request_options::RequestOptions,
+};
+
+/// Blocking API client for number orders
+///
+/// Blocking counterpart of [`endpoints::NumberOrderApi`].
+#[derive(Debug, Clone)]
+pub struct NumberOrderApi {
+    api: endpoints::NumberOrderApi,
+    runtime: Arc<Runtime>,
+}
+
+impl NumberOrderApi {
+    pub(crate) fn new(api: endpoints::NumberOrderApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List number orders
+    pub fn list(
+        &self,
+        params: Option<ListNumberOrdersParams>,
+    ) -> Result<ApiListResponse<NumberOrder>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all number orders, fetching pages on demand
+    pub fn list_all(&self, params: Option<ListNumberOrdersParams>) -> Paginator<NumberOrder> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get a number order by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Order phone numbers
+    pub fn create(
+        &self,
+        request: CreateNumberOrderRequest,
+    ) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.runtime.block_on(self.api.create(request))
+    }
+
+    /// Update a number order
+    pub fn update(
+        &self,
+        id: &str,
+        request: UpdateNumberOrderRequest,
+    ) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.runtime.block_on(self.api.update(id, request))
+    }
+
+    /// Get a sub number order by ID
+    pub fn get_sub_number_order(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<SubNumberOrder>, TelnyxError> {
+        self.runtime.block_on(self.api.get_sub_number_order(id))
+    }
+}
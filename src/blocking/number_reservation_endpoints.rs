@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateNumberReservationRequest, ListNumberReservationsParams,
+        NumberReservation,
+    },
+    request_options::RequestOptions,
+};
+
+/// Blocking API client for number reservations
+///
+/// Blocking counterpart of [`endpoints::NumberReservationApi`].
+#[derive(Debug, Clone)]
+pub struct NumberReservationApi {
+    api: endpoints::NumberReservationApi,
+    runtime: Arc<Runtime>,
+}
+
+impl NumberReservationApi {
+    pub(crate) fn new(api: endpoints::NumberReservationApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List number reservations
+    pub fn list(
+        &self,
+        params: Option<ListNumberReservationsParams>,
+    ) -> Result<ApiListResponse<NumberReservation>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all number reservations, fetching pages on demand
+    pub fn list_all(
+        &self,
+        params: Option<ListNumberReservationsParams>,
+    ) -> Paginator<NumberReservation> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get a number reservation by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Reserve phone numbers
+    pub fn create(
+        &self,
+        request: CreateNumberReservationRequest,
+    ) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.runtime.block_on(self.api.create(request))
+    }
+
+    /// Extend a number reservation before it expires
+    pub fn extend(&self, id: &str) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.runtime.block_on(self.api.extend(id))
+    }
+}
@@ -0,0 +1,48 @@
+use futures::StreamExt;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use crate::{error::TelnyxError, pagination};
+
+/// Blocking iterator over a list endpoint, fetching pages on demand
+///
+/// Blocking counterpart of [`crate::Paginator`].
+pub struct Paginator<T> {
+    inner: pagination::Paginator<'static, T>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Paginator<T> {
+    pub(crate) fn new(inner: pagination::Paginator<'static, T>, runtime: Arc<Runtime>) -> Self {
+        Self { inner, runtime }
+    }
+
+    /// Stop after fetching `pages` pages, as a safety cap on large result sets
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.inner = self.inner.max_pages(pages);
+        self
+    }
+
+    /// Stop after yielding `items` items
+    pub fn max_items(mut self, items: usize) -> Self {
+        self.inner = self.inner.max_items(items);
+        self
+    }
+
+    /// Fetch every remaining page and collect the items
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching a page.
+    pub fn collect_all(self) -> Result<Vec<T>, TelnyxError> {
+        self.collect()
+    }
+}
+
+impl<T> Iterator for Paginator<T> {
+    type Item = Result<T, TelnyxError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.inner.next())
+    }
+}
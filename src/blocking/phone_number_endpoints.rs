@@ -0,0 +1,103 @@
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use super::Paginator;
+use crate::{
+    endpoints,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, ListPhoneNumbersParams, MessagingPhoneNumber, PhoneNumber,
+        PhoneNumberVoiceSettings, UpdatePhoneNumberMessagingRequest, UpdatePhoneNumberRequest,
+        UpdatePhoneNumberVoiceSettingsRequest,
+    },
+    request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// Blocking API client for the phone numbers owned by the account
+///
+/// Blocking counterpart of [`endpoints::PhoneNumberApi`].
+#[derive(Debug, Clone)]
+pub struct PhoneNumberApi {
+    api: endpoints::PhoneNumberApi,
+    runtime: Arc<Runtime>,
+}
+
+impl PhoneNumberApi {
+    pub(crate) fn new(api: endpoints::PhoneNumberApi, runtime: Arc<Runtime>) -> Self {
+        Self { api, runtime }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.api = self.api.with_options(options);
+        self
+    }
+
+    /// List phone numbers
+    pub fn list(
+        &self,
+        params: Option<ListPhoneNumbersParams>,
+    ) -> Result<ApiListResponse<PhoneNumber>, TelnyxError> {
+        self.runtime.block_on(self.api.list(params))
+    }
+
+    /// Iterate over all phone numbers, fetching pages on demand
+    pub fn list_all(&self, params: Option<ListPhoneNumbersParams>) -> Paginator<PhoneNumber> {
+        Paginator::new(self.api.list_all(params), self.runtime.clone())
+    }
+
+    /// Get a phone number by ID
+    pub fn get(&self, id: &str) -> Result<ApiResponse<PhoneNumber>, TelnyxError> {
+        self.runtime.block_on(self.api.get(id))
+    }
+
+    /// Update a phone number
+    pub fn update(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberRequest,
+    ) -> Result<ApiResponse<PhoneNumber>, TelnyxError> {
+        self.runtime.block_on(self.api.update(id, request))
+    }
+
+    /// Delete a phone number, releasing it from the account
+    pub fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.runtime.block_on(self.api.delete(id))
+    }
+
+    /// Get the voice settings of a phone number
+    pub fn get_voice(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<PhoneNumberVoiceSettings>, TelnyxError> {
+        self.runtime.block_on(self.api.get_voice(id))
+    }
+
+    /// Update the voice settings of a phone number
+    pub fn update_voice(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberVoiceSettingsRequest,
+    ) -> Result<ApiResponse<PhoneNumberVoiceSettings>, TelnyxError> {
+        self.runtime.block_on(self.api.update_voice(id, request))
+    }
+
+    /// Get the messaging settings of a phone number
+    pub fn get_messaging(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.runtime.block_on(self.api.get_messaging(id))
+    }
+
+    /// Update the messaging settings of a phone number
+    pub fn update_messaging(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberMessagingRequest,
+    ) -> Result<ApiResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.runtime
+            .block_on(self.api.update_messaging(id, request))
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Serialize, de::DeserializeOwned};
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use crate::{error::TelnyxError, request_options::RequestOptions, response::ResponseMeta};
+
+/// A request to an arbitrary API path, created with
+/// [`TelnyxClient::request()`](super::TelnyxClient::request)
+///
+/// Blocking counterpart of [`crate::RequestBuilder`].
+#[derive(Debug)]
+pub struct RequestBuilder {
+    request: crate::RequestBuilder,
+    runtime: Arc<Runtime>,
+}
+
+impl RequestBuilder {
+    pub(crate) fn new(request: crate::RequestBuilder, runtime: Arc<Runtime>) -> Self {
+        Self { request, runtime }
+    }
+
+    /// Append query parameters
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        self.request = self.request.query(query);
+        self
+    }
+
+    /// Send `body` as JSON
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        self.request = self.request.json(body);
+        self
+    }
+
+    /// Use `options` for this request
+    pub fn options(mut self, options: RequestOptions) -> Self {
+        self.request = self.request.options(options);
+        self
+    }
+
+    /// Send the request and deserialize the response body
+    pub fn send<T: DeserializeOwned>(self) -> Result<T, TelnyxError> {
+        self.runtime.block_on(self.request.send())
+    }
+
+    /// Send the request and discard the response body
+    pub fn send_empty(self) -> Result<ResponseMeta, TelnyxError> {
+        self.runtime.block_on(self.request.send_empty())
+    }
+}
@@ -0,0 +1,260 @@
+use bon::Builder;
+use std::{
+    collections::VecDeque,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::error::TelnyxError;
+
+/// Circuit breaker settings for a [`TelnyxClient`](crate::TelnyxClient)
+///
+/// The breaker tracks the outcome of the most recent attempts. Timeouts,
+/// connection failures and 5xx responses count as failures. 429 responses
+/// are not counted either way: they mean the client is over its quota, not
+/// that the API is unhealthy, and are paced by the retry policy and rate
+/// limiter instead. Once the failure
+/// rate reaches `failure_rate_threshold`, the circuit opens and requests fail
+/// immediately with [`TelnyxError::CircuitOpen`] for `open_duration`. After
+/// that, up to `half_open_probes` requests are let through: the circuit
+/// closes when they all succeed and opens again on the first failure.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use telnyx_rs::{CircuitBreakerPolicy, TelnyxClient};
+///
+/// # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+/// let client = TelnyxClient::builder()
+///     .api_key("your-api-key")
+///     .circuit_breaker(
+///         CircuitBreakerPolicy::builder()
+///             .failure_rate_threshold(0.5)
+///             .open_duration(Duration::from_secs(60))
+///             .build(),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct CircuitBreakerPolicy {
+    /// Failure rate, between 0 and 1, at which the circuit opens (defaults to 0.5)
+    #[builder(default = 0.5)]
+    pub failure_rate_threshold: f64,
+    /// Number of most recent attempts the failure rate is computed over (defaults to 20)
+    #[builder(default = 20)]
+    pub window_size: u32,
+    /// Attempts required in the window before the circuit can open (defaults to 10)
+    #[builder(default = 10)]
+    pub minimum_requests: u32,
+    /// How long the circuit stays open before probing, at most one day (defaults to 30 seconds)
+    #[builder(default = Duration::from_secs(30))]
+    pub open_duration: Duration,
+    /// Requests let through while half-open, all of which must succeed to close the circuit (defaults to 1)
+    #[builder(default = 1)]
+    pub half_open_probes: u32,
+}
+
+impl Default for CircuitBreakerPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Longest accepted [`CircuitBreakerPolicy::open_duration`]
+const MAX_OPEN_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// State of a circuit breaker, for health checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitState {
+    /// Requests are sent normally
+    Closed,
+    /// Requests fail immediately
+    Open,
+    /// A limited number of probe requests are sent to test recovery
+    HalfOpen,
+}
+
+/// Runtime state of a [`CircuitBreakerPolicy`]
+pub(crate) struct CircuitBreaker {
+    policy: CircuitBreakerPolicy,
+    state: Mutex<State>,
+}
+
+enum State {
+    Closed { outcomes: VecDeque<bool> },
+    Open { until: Instant },
+    HalfOpen { in_flight: u32, successes: u32 },
+}
+
+/// Outcome of a single attempt, as seen by the circuit breaker
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Success,
+    Failure,
+    /// The attempt says nothing about the API's health, e.g. a request
+    /// rejected by middleware
+    Ignored,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn new(policy: CircuitBreakerPolicy) -> Result<Self, TelnyxError> {
+        if !(policy.failure_rate_threshold > 0.0 && policy.failure_rate_threshold <= 1.0) {
+            return Err(TelnyxError::Config(
+                "Circuit breaker failure rate threshold must be in (0, 1]".into(),
+            ));
+        }
+        if policy.window_size == 0
+            || policy.minimum_requests == 0
+            || policy.minimum_requests > policy.window_size
+        {
+            return Err(TelnyxError::Config(
+                "Circuit breaker requires 0 < minimum_requests <= window_size".into(),
+            ));
+        }
+        if policy.half_open_probes == 0 || policy.open_duration.is_zero() {
+            return Err(TelnyxError::Config(
+                "Circuit breaker requires a non-zero open duration and number of probes".into(),
+            ));
+        }
+        if policy.open_duration > MAX_OPEN_DURATION {
+            return Err(TelnyxError::Config(
+                "Circuit breaker open duration must be at most one day".into(),
+            ));
+        }
+
+        Ok(Self {
+            policy,
+            state: Mutex::new(State::closed()),
+        })
+    }
+
+    /// The current state, moving from open to half-open once the open
+    /// duration has elapsed
+    pub(crate) fn state(&self) -> CircuitState {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        self.expire(&mut state);
+        match *state {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// Permission to send an attempt
+    ///
+    /// The returned permit must be completed with the attempt's outcome;
+    /// dropping it releases a half-open probe slot without an outcome.
+    pub(crate) fn acquire(&self) -> Result<Permit<'_>, TelnyxError> {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        self.expire(&mut state);
+        let probe = match &mut *state {
+            State::Closed { .. } => false,
+            State::Open { until } => {
+                return Err(TelnyxError::CircuitOpen {
+                    retry_after: until.saturating_duration_since(Instant::now()),
+                });
+            }
+            State::HalfOpen { in_flight, .. } if *in_flight >= self.policy.half_open_probes => {
+                return Err(TelnyxError::CircuitOpen {
+                    retry_after: Duration::ZERO,
+                });
+            }
+            State::HalfOpen { in_flight, .. } => {
+                *in_flight += 1;
+                true
+            }
+        };
+
+        Ok(Permit {
+            breaker: self,
+            probe,
+            completed: false,
+        })
+    }
+
+    fn expire(&self, state: &mut State) {
+        if let State::Open { until } = state
+            && Instant::now() >= *until
+        {
+            *state = State::HalfOpen {
+                in_flight: 0,
+                successes: 0,
+            };
+        }
+    }
+
+    fn record(&self, probe: bool, outcome: Outcome) {
+        let mut state = self.state.lock().unwrap_or_else(|p| p.into_inner());
+        let now = Instant::now();
+        // `new` bounds the open duration, so this only fails on a broken clock
+        let open = State::Open {
+            until: now.checked_add(self.policy.open_duration).unwrap_or(now),
+        };
+
+        match &mut *state {
+            State::Closed { outcomes } if outcome != Outcome::Ignored => {
+                outcomes.push_back(outcome == Outcome::Failure);
+                if outcomes.len() > self.policy.window_size as usize {
+                    outcomes.pop_front();
+                }
+                let failures = outcomes.iter().filter(|failed| **failed).count();
+                if outcomes.len() >= self.policy.minimum_requests as usize
+                    && failures as f64 / outcomes.len() as f64 >= self.policy.failure_rate_threshold
+                {
+                    *state = open;
+                }
+            }
+            State::HalfOpen {
+                in_flight,
+                successes,
+            } if probe => {
+                *in_flight = in_flight.saturating_sub(1);
+                match outcome {
+                    Outcome::Failure => *state = open,
+                    Outcome::Success => {
+                        *successes += 1;
+                        if *successes >= self.policy.half_open_probes {
+                            *state = State::closed();
+                        }
+                    }
+                    Outcome::Ignored => {}
+                }
+            }
+            // Outcomes of attempts started in an earlier state are stale
+            _ => {}
+        }
+    }
+}
+
+impl State {
+    fn closed() -> Self {
+        State::Closed {
+            outcomes: VecDeque::new(),
+        }
+    }
+}
+
+/// Permission to send one attempt through a [`CircuitBreaker`]
+pub(crate) struct Permit<'a> {
+    breaker: &'a CircuitBreaker,
+    probe: bool,
+    completed: bool,
+}
+
+impl Permit<'_> {
+    pub(crate) fn complete(mut self, outcome: Outcome) {
+        self.completed = true;
+        self.breaker.record(self.probe, outcome);
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.completed {
+            self.breaker.record(self.probe, Outcome::Ignored);
+        }
+    }
+}
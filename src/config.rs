@@ -0,0 +1,309 @@
+use serde::Deserialize;
+use std::{collections::HashMap, path::Path, time::Duration};
+
+use crate::{
+    client::{TelnyxClient, TelnyxClientBuilder},
+    error::TelnyxError,
+    models::redacted,
+    retry::RetryPolicy,
+};
+
+/// Environment variable naming the profile [`TelnyxClient::from_env()`] loads
+pub const PROFILE_ENV: &str = "TELNYX_PROFILE";
+/// Environment variable with the path of the profiles file (defaults to `telnyx.toml`)
+pub const CONFIG_FILE_ENV: &str = "TELNYX_CONFIG_FILE";
+
+const DEFAULT_CONFIG_FILE: &str = "telnyx.toml";
+
+/// Client settings loaded from the environment or a profiles file
+///
+/// Unset fields keep the builder's defaults when applied with
+/// [`TelnyxClientBuilder::config()`].
+///
+/// # Profiles file
+///
+/// ```toml
+/// [profiles.production]
+/// api_key = "KEY0123456789"
+///
+/// [profiles.staging]
+/// api_key = "KEY9876543210"
+/// base_url = "https://staging.example.com/v2"
+/// timeout_secs = 10
+///
+/// [profiles.staging.retry]
+/// max_attempts = 5
+/// base_delay_ms = 200
+/// ```
+///
+/// The `Debug` output redacts the API key.
+#[derive(Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientConfig {
+    /// The API key
+    pub api_key: Option<String>,
+    /// The base URL of the API
+    pub base_url: Option<String>,
+    /// Total request timeout in seconds
+    pub timeout_secs: Option<u64>,
+    /// Retry settings
+    #[serde(default)]
+    pub retry: RetryConfig,
+}
+
+impl std::fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("api_key", &redacted(self.api_key.as_deref()))
+            .field("base_url", &self.base_url)
+            .field("timeout_secs", &self.timeout_secs)
+            .field("retry", &self.retry)
+            .finish()
+    }
+}
+
+/// Retry settings of a [`ClientConfig`], applied on top of [`RetryPolicy::default()`]
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RetryConfig {
+    /// Total number of attempts, including the first one
+    pub max_attempts: Option<u32>,
+    /// Delay before the first retry in milliseconds
+    pub base_delay_ms: Option<u64>,
+    /// Upper bound for a single delay in milliseconds
+    pub max_delay_ms: Option<u64>,
+    /// Whether to randomize delays
+    pub jitter: Option<bool>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ProfilesFile {
+    #[serde(default)]
+    profiles: HashMap<String, ClientConfig>,
+}
+
+impl ClientConfig {
+    /// Load settings from environment variables
+    ///
+    /// | Variable | Setting |
+    /// |---|---|
+    /// | `TELNYX_API_KEY` | `api_key` |
+    /// | `TELNYX_BASE_URL` | `base_url` |
+    /// | `TELNYX_TIMEOUT_SECS` | `timeout_secs` |
+    /// | `TELNYX_RETRY_MAX_ATTEMPTS` | `retry.max_attempts` |
+    /// | `TELNYX_RETRY_BASE_DELAY_MS` | `retry.base_delay_ms` |
+    /// | `TELNYX_RETRY_MAX_DELAY_MS` | `retry.max_delay_ms` |
+    /// | `TELNYX_RETRY_JITTER` | `retry.jitter` |
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable is set but cannot be parsed.
+    pub fn from_env() -> Result<Self, TelnyxError> {
+        Self::from_env_with(process_env)
+    }
+
+    /// Load settings from the variables returned by `lookup`
+    ///
+    /// Reads the same variables as [`ClientConfig::from_env()`], from any
+    /// source, such as a map in tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use telnyx_rs::ClientConfig;
+    ///
+    /// # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+    /// let vars = HashMap::from([("TELNYX_TIMEOUT_SECS", "5")]);
+    /// let config = ClientConfig::from_env_with(|name| vars.get(name).map(|v| v.to_string()))?;
+    /// assert_eq!(config.timeout_secs, Some(5));
+    /// # Ok(())
+    /// # }
+    /// # example().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable is set but cannot be parsed.
+    pub fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, TelnyxError> {
+        Ok(Self {
+            api_key: env_var(&lookup, "TELNYX_API_KEY")?,
+            base_url: env_var(&lookup, "TELNYX_BASE_URL")?,
+            timeout_secs: env_var(&lookup, "TELNYX_TIMEOUT_SECS")?,
+            retry: RetryConfig {
+                max_attempts: env_var(&lookup, "TELNYX_RETRY_MAX_ATTEMPTS")?,
+                base_delay_ms: env_var(&lookup, "TELNYX_RETRY_BASE_DELAY_MS")?,
+                max_delay_ms: env_var(&lookup, "TELNYX_RETRY_MAX_DELAY_MS")?,
+                jitter: env_var(&lookup, "TELNYX_RETRY_JITTER")?,
+            },
+        })
+    }
+
+    /// Load a named profile from a TOML profiles file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or parsed, or if it has
+    /// no such profile.
+    pub fn from_profile_file(path: impl AsRef<Path>, profile: &str) -> Result<Self, TelnyxError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path)
+            .map_err(|e| TelnyxError::Config(format!("Failed to read {}: {e}", path.display())))?;
+        Self::from_profile_str(&contents, profile)
+    }
+
+    /// Load a named profile from the contents of a TOML profiles file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the contents cannot be parsed, or if they have
+    /// no such profile.
+    pub fn from_profile_str(contents: &str, profile: &str) -> Result<Self, TelnyxError> {
+        let mut file: ProfilesFile = toml::from_str(contents)
+            .map_err(|e| TelnyxError::Config(format!("Invalid profiles file: {e}")))?;
+        file.profiles
+            .remove(profile)
+            .ok_or_else(|| TelnyxError::Config(format!("Profile '{profile}' not found")))
+    }
+
+    /// Overlay the settings that are set in `other` onto `self`
+    pub fn merge(self, other: ClientConfig) -> Self {
+        Self {
+            api_key: other.api_key.or(self.api_key),
+            base_url: other.base_url.or(self.base_url),
+            timeout_secs: other.timeout_secs.or(self.timeout_secs),
+            retry: RetryConfig {
+                max_attempts: other.retry.max_attempts.or(self.retry.max_attempts),
+                base_delay_ms: other.retry.base_delay_ms.or(self.retry.base_delay_ms),
+                max_delay_ms: other.retry.max_delay_ms.or(self.retry.max_delay_ms),
+                jitter: other.retry.jitter.or(self.retry.jitter),
+            },
+        }
+    }
+
+    fn retry_policy(&self, base: RetryPolicy) -> Option<RetryPolicy> {
+        let retry = &self.retry;
+        if retry.max_attempts.is_none()
+            && retry.base_delay_ms.is_none()
+            && retry.max_delay_ms.is_none()
+            && retry.jitter.is_none()
+        {
+            return None;
+        }
+
+        Some(RetryPolicy {
+            max_attempts: retry.max_attempts.unwrap_or(base.max_attempts),
+            base_delay: retry
+                .base_delay_ms
+                .map_or(base.base_delay, Duration::from_millis),
+            max_delay: retry
+                .max_delay_ms
+                .map_or(base.max_delay, Duration::from_millis),
+            jitter: retry.jitter.unwrap_or(base.jitter),
+        })
+    }
+}
+
+impl TelnyxClient {
+    /// Build a client from the environment
+    ///
+    /// When `TELNYX_PROFILE` is set, that profile is loaded from the file
+    /// named by `TELNYX_CONFIG_FILE` (defaults to `telnyx.toml`). Settings
+    /// from environment variables (see [`ClientConfig::from_env()`]) take
+    /// precedence over the profile.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use telnyx_rs::TelnyxClient;
+    ///
+    /// # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+    /// // TELNYX_API_KEY=KEY0123456789 or TELNYX_PROFILE=staging
+    /// let client = TelnyxClient::from_env()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable or the profiles file is invalid, or if
+    /// no API key is configured.
+    pub fn from_env() -> Result<Self, TelnyxError> {
+        Self::from_env_with(process_env)
+    }
+
+    /// Build a client from the variables returned by `lookup`
+    ///
+    /// Behaves like [`TelnyxClient::from_env()`], reading variables from any
+    /// source instead of the process environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a variable or the profiles file is invalid, or if
+    /// no API key is configured.
+    pub fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> Result<Self, TelnyxError> {
+        let mut config = ClientConfig::default();
+
+        if let Some(profile) = env_var::<String>(&lookup, PROFILE_ENV)? {
+            let path = env_var::<String>(&lookup, CONFIG_FILE_ENV)?
+                .unwrap_or_else(|| DEFAULT_CONFIG_FILE.to_string());
+            config = ClientConfig::from_profile_file(path, &profile)?;
+        }
+
+        let config = config.merge(ClientConfig::from_env_with(lookup)?);
+        Self::builder().config(config).build()
+    }
+
+    /// Build a client from a named profile in a TOML profiles file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the profile cannot be loaded or the client fails
+    /// to build.
+    pub fn from_profile(path: impl AsRef<Path>, profile: &str) -> Result<Self, TelnyxError> {
+        let config = ClientConfig::from_profile_file(path, profile)?;
+        Self::builder().config(config).build()
+    }
+}
+
+impl TelnyxClientBuilder {
+    /// Apply the settings that are set in a [`ClientConfig`]
+    pub fn config(mut self, config: ClientConfig) -> Self {
+        if let Some(policy) = config.retry_policy(self.retry_policy_or_default()) {
+            self = self.retry_policy(policy);
+        }
+        if let Some(api_key) = config.api_key {
+            self = self.api_key(api_key);
+        }
+        if let Some(base_url) = config.base_url {
+            self = self.base_url(base_url);
+        }
+        if let Some(timeout) = config.timeout_secs {
+            self = self.timeout(Duration::from_secs(timeout));
+        }
+        self
+    }
+}
+
+fn env_var<T: std::str::FromStr>(
+    lookup: impl Fn(&str) -> Option<String>,
+    name: &str,
+) -> Result<Option<T>, TelnyxError>
+where
+    T::Err: std::fmt::Display,
+{
+    match lookup(name) {
+        Some(value) if value.trim().is_empty() => Ok(None),
+        Some(value) => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|e| TelnyxError::Config(format!("Invalid {name}: {e}"))),
+        None => Ok(None),
+    }
+}
+
+/// Read a variable from the process environment, replacing invalid Unicode
+fn process_env(name: &str) -> Option<String> {
+    std::env::var_os(name).map(|value| value.to_string_lossy().into_owned())
+}
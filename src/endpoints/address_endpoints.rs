@@ -3,25 +3,61 @@ use crate::{
     error::TelnyxError,
     models::{
         Address, AddressAcceptSuggestionRequest, AddressAcceptSuggestionResult, ApiListResponse,
-        ApiResponse, CreateAddressRequest, ValidateAddressRequest, ValidateAddressResult,
+        ApiResponse, CreateAddressRequest, ListAddressesParams, ValidateAddressRequest,
+        ValidateAddressResult,
     },
+    pagination::Paginator,
+    query::ToQuery,
+    request_options::RequestOptions,
+    response::ResponseMeta,
 };
 
 /// API client for addresses
-pub struct AddressApi<'a> {
-    client: &'a TelnyxClient,
+#[derive(Debug, Clone)]
+pub struct AddressApi {
+    client: TelnyxClient,
+    options: RequestOptions,
 }
 
-impl<'a> AddressApi<'a> {
-    pub(crate) fn new(client: &'a TelnyxClient) -> Self {
-        Self { client }
+impl AddressApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    ///
+    /// Replaces any options set earlier on this handle. An idempotency key
+    /// makes the next create safe to retry.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use std::time::Duration;
+    /// # use telnyx_rs::{RequestOptions, TelnyxClient};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let options = RequestOptions::new().timeout(Duration::from_secs(120));
+    /// let addresses = client
+    ///     .addresses()
+    ///     .with_options(options)
+    ///     .list_all(None)
+    ///     .collect_all()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
     }
 
     /// List all addresses
     ///
     /// # Arguments
     ///
-    /// * `params` - Optional pagination parameters
+    /// * `params` - Optional pagination, filter and sort parameters
     ///
     /// # Example
     ///
@@ -30,11 +66,49 @@ impl<'a> AddressApi<'a> {
     /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
     /// // List with defaults
     /// let addresses = client.addresses().list(None).await?;
+    ///
+    /// // List the second page of addresses in the address book
+    /// let params = ListAddressesParams::builder()
+    ///     .page_number(2)
+    ///     .page_size(50)
+    ///     .address_book_eq(true)
+    ///     .build();
+    /// let addresses = client.addresses().list(Some(params)).await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn list(&self) -> Result<ApiListResponse<Address>, TelnyxError> {
-        self.client.get("/addresses").await
+    pub async fn list(
+        &self,
+        params: Option<ListAddressesParams>,
+    ) -> Result<ApiListResponse<Address>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/addresses",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all addresses, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter and sort parameters. `page_number` sets
+    ///   the first page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let addresses = client.addresses().list_all(None).collect_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(&self, params: Option<ListAddressesParams>) -> Paginator<'static, Address> {
+        self.client
+            .paginate("/addresses", params.unwrap_or_default(), &self.options)
     }
 
     /// Get an address by ID
@@ -53,10 +127,12 @@ impl<'a> AddressApi<'a> {
     /// # }
     /// ```
     pub async fn get(&self, id: &str) -> Result<ApiResponse<Address>, TelnyxError> {
-        self.client.get(&format!(".address/{}", id)).await
+        self.client
+            .get(&format!("/addresses/{}", id), &self.options)
+            .await
     }
 
-    // Create a new address
+    /// Create a new address
     ///
     /// # Arguments
     ///
@@ -85,11 +161,15 @@ impl<'a> AddressApi<'a> {
         &self,
         request: CreateAddressRequest,
     ) -> Result<ApiResponse<Address>, TelnyxError> {
-        self.client.post("/address", &request).await
+        self.client
+            .post("/addresses", &request, &self.options)
+            .await
     }
 
     /// Delete an address
     ///
+    /// Returns the response metadata, which carries the request ID.
+    ///
     /// # Arguments
     ///
     /// * `id` - The address ID to delete
@@ -99,12 +179,15 @@ impl<'a> AddressApi<'a> {
     /// ```no_run
     /// # use telnyx_rs::TelnyxClient;
     /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
-    /// client.addresses().delete("1234567890").await?;
+    /// let meta = client.addresses().delete("1234567890").await?;
+    /// println!("Deleted in request {:?}", meta.request_id);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn delete(&self, id: &str) -> Result<(), TelnyxError> {
-        self.client.delete(&format!(".address/{}", id)).await
+    pub async fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.client
+            .delete(&format!("/addresses/{}", id), &self.options)
+            .await
     }
 
     /// Validate an address for emergency services
@@ -133,7 +216,7 @@ impl<'a> AddressApi<'a> {
         request: ValidateAddressRequest,
     ) -> Result<ApiResponse<ValidateAddressResult>, TelnyxError> {
         self.client
-            .post("/addresses/actions/validate", &request)
+            .post("/addresses/actions/validate", &request, &self.options)
             .await
     }
 
@@ -163,6 +246,7 @@ impl<'a> AddressApi<'a> {
             .post(
                 &format!("/addresses/{}/actions/accept_suggestions", id),
                 &request,
+                &self.options,
             )
             .await
     }
@@ -0,0 +1,67 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{AvailablePhoneNumbersResponse, PhoneNumberSearchFilter},
+    query::ToQuery,
+    request_options::RequestOptions,
+};
+
+/// API client for searching phone numbers available to order
+#[derive(Debug, Clone)]
+pub struct AvailablePhoneNumberApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl AvailablePhoneNumberApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Search for phone numbers available to order
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Optional search filters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{PhoneNumberFeature, PhoneNumberSearchFilter}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let filter = PhoneNumberSearchFilter::builder()
+    ///     .country_code("US")
+    ///     .locality("Chicago")
+    ///     .features(vec![PhoneNumberFeature::Sms, PhoneNumberFeature::Voice])
+    ///     .limit(5)
+    ///     .build();
+    ///
+    /// let available = client.available_phone_numbers().search(Some(filter)).await?;
+    /// for number in available.data {
+    ///     println!("{}", number.phone_number);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn search(
+        &self,
+        filter: Option<PhoneNumberSearchFilter>,
+    ) -> Result<AvailablePhoneNumbersResponse, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/available_phone_numbers",
+                &filter.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+}
@@ -0,0 +1,275 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        AllocatableGlobalOutboundChannels, ApiListResponse, ApiResponse,
+        CreateManagedAccountRequest, EnableManagedAccountRequest, ListManagedAccountsParams,
+        ManagedAccount, UpdateManagedAccountRequest,
+    },
+    pagination::Paginator,
+    query::ToQuery,
+    request_options::RequestOptions,
+};
+
+/// API client for managed accounts
+///
+/// Manages the sub-accounts of a manager account. To call other endpoints as
+/// a managed account, scope the client with
+/// [`TelnyxClient::for_managed_account()`].
+#[derive(Debug, Clone)]
+pub struct ManagedAccountApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl ManagedAccountApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// List managed accounts
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional pagination, filter and sort parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::ListManagedAccountsParams};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let params = ListManagedAccountsParams::builder()
+    ///     .organization_name_contains("Acme")
+    ///     .build();
+    /// let accounts = client.managed_accounts().list(Some(params)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        params: Option<ListManagedAccountsParams>,
+    ) -> Result<ApiListResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/managed_accounts",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all managed accounts, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter and sort parameters. `page_number` sets
+    ///   the first page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let accounts = client.managed_accounts().list_all(None).collect_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        params: Option<ListManagedAccountsParams>,
+    ) -> Paginator<'static, ManagedAccount> {
+        self.client.paginate(
+            "/managed_accounts",
+            params.unwrap_or_default(),
+            &self.options,
+        )
+    }
+
+    /// Get a managed account by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The managed account ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let account = client.managed_accounts().get("f65ceda4-6522-4ad6-aede-98de83385123").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .get(&format!("/managed_accounts/{}", id), &self.options)
+            .await
+    }
+
+    /// Create a new managed account
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The managed account creation request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::CreateManagedAccountRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = CreateManagedAccountRequest::builder()
+    ///     .business_name("Acme Inc")
+    ///     .email("admin@acme.example")
+    ///     .rollup_billing(true)
+    ///     .build();
+    ///
+    /// let account = client.managed_accounts().create(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        request: CreateManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .post("/managed_accounts", &request, &self.options)
+            .await
+    }
+
+    /// Update a managed account
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The managed account ID
+    /// * `request` - The fields to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::UpdateManagedAccountRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdateManagedAccountRequest::builder()
+    ///     .managed_account_allow_custom_pricing(true)
+    ///     .build();
+    ///
+    /// let account = client
+    ///     .managed_accounts()
+    ///     .update("f65ceda4-6522-4ad6-aede-98de83385123", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: &str,
+        request: UpdateManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .patch(
+                &format!("/managed_accounts/{}", id),
+                &request,
+                &self.options,
+            )
+            .await
+    }
+
+    /// Enable a disabled managed account
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The managed account ID
+    /// * `request` - Whether to also re-enable the account's connections
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::EnableManagedAccountRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = EnableManagedAccountRequest::builder()
+    ///     .reenable_all_connections(true)
+    ///     .build();
+    ///
+    /// let account = client
+    ///     .managed_accounts()
+    ///     .enable("f65ceda4-6522-4ad6-aede-98de83385123", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable(
+        &self,
+        id: &str,
+        request: EnableManagedAccountRequest,
+    ) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .post(
+                &format!("/managed_accounts/{}/actions/enable", id),
+                &request,
+                &self.options,
+            )
+            .await
+    }
+
+    /// Disable a managed account, along with its connections
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The managed account ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let account = client
+    ///     .managed_accounts()
+    ///     .disable("f65ceda4-6522-4ad6-aede-98de83385123")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable(&self, id: &str) -> Result<ApiResponse<ManagedAccount>, TelnyxError> {
+        self.client
+            .post(
+                &format!("/managed_accounts/{}/actions/disable", id),
+                &serde_json::Map::new(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Get the global outbound channels that can still be allocated to
+    /// managed accounts
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let channels = client
+    ///     .managed_accounts()
+    ///     .allocatable_global_outbound_channels()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn allocatable_global_outbound_channels(
+        &self,
+    ) -> Result<ApiResponse<AllocatableGlobalOutboundChannels>, TelnyxError> {
+        self.client
+            .get(
+                "/managed_accounts/allocatable_global_outbound_channels",
+                &self.options,
+            )
+            .await
+    }
+}
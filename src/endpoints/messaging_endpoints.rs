@@ -0,0 +1,192 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        ApiResponse, Message, SendGroupMmsRequest, SendMessageRequest,
+        SendNumberPoolMessageRequest, SendShortCodeMessageRequest,
+    },
+    request_options::RequestOptions,
+};
+
+/// API client for sending and retrieving SMS and MMS messages
+#[derive(Debug, Clone)]
+pub struct MessagingApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl MessagingApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    ///
+    /// An idempotency key makes the next send safe to retry without
+    /// delivering the message twice. Later sends through this handle do not
+    /// reuse the key.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{RequestOptions, TelnyxClient, models::SendMessageRequest};
+    /// # async fn example(client: &TelnyxClient, request: SendMessageRequest) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let message = client
+    ///     .messages()
+    ///     .with_options(RequestOptions::new().idempotency_key("order-1234-confirmation"))
+    ///     .send(request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Send an SMS or MMS message
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The message to send
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::SendMessageRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = SendMessageRequest::builder()
+    ///     .from("+13125550000")
+    ///     .to("+13125550001")
+    ///     .text("Your order has shipped")
+    ///     .build();
+    ///
+    /// let message = client.messages().send(request).await?;
+    /// println!("Sent message {}", message.data.id);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(
+        &self,
+        request: SendMessageRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.client.post("/messages", &request, &self.options).await
+    }
+
+    /// Send a message from a number in a messaging profile's number pool
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The message to send
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::SendNumberPoolMessageRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = SendNumberPoolMessageRequest::builder()
+    ///     .messaging_profile_id("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d")
+    ///     .to("+13125550001")
+    ///     .text("Your code is 123456")
+    ///     .build();
+    ///
+    /// let message = client.messages().send_with_number_pool(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_with_number_pool(
+        &self,
+        request: SendNumberPoolMessageRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.client
+            .post("/messages/number_pool", &request, &self.options)
+            .await
+    }
+
+    /// Send a message from a short code
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The message to send
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::SendShortCodeMessageRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = SendShortCodeMessageRequest::builder()
+    ///     .from("12345")
+    ///     .to("+13125550001")
+    ///     .text("Reply STOP to unsubscribe")
+    ///     .build();
+    ///
+    /// let message = client.messages().send_short_code(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_short_code(
+        &self,
+        request: SendShortCodeMessageRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.client
+            .post("/messages/short_code", &request, &self.options)
+            .await
+    }
+
+    /// Send an MMS to a group of recipients
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The message to send
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::SendGroupMmsRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = SendGroupMmsRequest::builder()
+    ///     .from("+13125550000")
+    ///     .to(vec!["+13125550001".into(), "+13125550002".into()])
+    ///     .text("Team lunch at noon")
+    ///     .build();
+    ///
+    /// let message = client.messages().send_group_mms(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_group_mms(
+        &self,
+        request: SendGroupMmsRequest,
+    ) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.client
+            .post("/messages/group_mms", &request, &self.options)
+            .await
+    }
+
+    /// Get a message by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The message ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let message = client
+    ///     .messages()
+    ///     .get("40385f64-5717-4562-b3fc-2c963f66afa6")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<Message>, TelnyxError> {
+        self.client
+            .get(&format!("/messages/{}", id), &self.options)
+            .await
+    }
+}
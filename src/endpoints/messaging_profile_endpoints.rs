@@ -0,0 +1,316 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateMessagingProfileRequest, ListMessagingProfilesParams,
+        MessagingPhoneNumber, MessagingProfile, MessagingProfileMetrics, MetricsTimeFrame,
+        PageParams, ShortCode, UpdateMessagingProfileRequest,
+    },
+    pagination::Paginator,
+    query::{Query, ToQuery},
+    request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// API client for messaging profiles
+#[derive(Debug, Clone)]
+pub struct MessagingProfileApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl MessagingProfileApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// List messaging profiles
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional pagination and filter parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::ListMessagingProfilesParams};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let params = ListMessagingProfilesParams::builder()
+    ///     .name("Customer notifications")
+    ///     .build();
+    /// let profiles = client.messaging_profiles().list(Some(params)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        params: Option<ListMessagingProfilesParams>,
+    ) -> Result<ApiListResponse<MessagingProfile>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/messaging_profiles",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all messaging profiles, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter parameters. `page_number` sets the first
+    ///   page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let profiles = client.messaging_profiles().list_all(None).collect_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        params: Option<ListMessagingProfilesParams>,
+    ) -> Paginator<'static, MessagingProfile> {
+        self.client.paginate(
+            "/messaging_profiles",
+            params.unwrap_or_default(),
+            &self.options,
+        )
+    }
+
+    /// Get a messaging profile by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let profile = client
+    ///     .messaging_profiles()
+    ///     .get("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.client
+            .get(&format!("/messaging_profiles/{}", id), &self.options)
+            .await
+    }
+
+    /// Create a new messaging profile
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The messaging profile creation request
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{CreateMessagingProfileRequest, NumberPoolSettings}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = CreateMessagingProfileRequest::builder()
+    ///     .name("Customer notifications")
+    ///     .whitelisted_destinations(vec!["US".into(), "CA".into()])
+    ///     .webhook_url("https://example.com/webhooks/messaging")
+    ///     .number_pool_settings(
+    ///         NumberPoolSettings::builder()
+    ///             .toll_free_weight(1.0)
+    ///             .long_code_weight(2.0)
+    ///             .sticky_sender(true)
+    ///             .build(),
+    ///     )
+    ///     .build();
+    ///
+    /// let profile = client.messaging_profiles().create(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        request: CreateMessagingProfileRequest,
+    ) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.client
+            .post("/messaging_profiles", &request, &self.options)
+            .await
+    }
+
+    /// Update a messaging profile
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID
+    /// * `request` - The fields to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::UpdateMessagingProfileRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdateMessagingProfileRequest::builder()
+    ///     .enabled(false)
+    ///     .build();
+    ///
+    /// let profile = client
+    ///     .messaging_profiles()
+    ///     .update("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: &str,
+        request: UpdateMessagingProfileRequest,
+    ) -> Result<ApiResponse<MessagingProfile>, TelnyxError> {
+        self.client
+            .patch(
+                &format!("/messaging_profiles/{}", id),
+                &request,
+                &self.options,
+            )
+            .await
+    }
+
+    /// Delete a messaging profile
+    ///
+    /// Returns the response metadata, which carries the request ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID to delete
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// client
+    ///     .messaging_profiles()
+    ///     .delete("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.client
+            .delete(&format!("/messaging_profiles/{}", id), &self.options)
+            .await
+    }
+
+    /// List the phone numbers assigned to a messaging profile
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID
+    /// * `params` - Optional pagination parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let numbers = client
+    ///     .messaging_profiles()
+    ///     .list_phone_numbers("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d", None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_phone_numbers(
+        &self,
+        id: &str,
+        params: Option<PageParams>,
+    ) -> Result<ApiListResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.client
+            .get_with_query(
+                &format!("/messaging_profiles/{}/phone_numbers", id),
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// List the short codes assigned to a messaging profile
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID
+    /// * `params` - Optional pagination parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let short_codes = client
+    ///     .messaging_profiles()
+    ///     .list_short_codes("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d", None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_short_codes(
+        &self,
+        id: &str,
+        params: Option<PageParams>,
+    ) -> Result<ApiListResponse<ShortCode>, TelnyxError> {
+        self.client
+            .get_with_query(
+                &format!("/messaging_profiles/{}/short_codes", id),
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Get message metrics for a messaging profile
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The messaging profile ID
+    /// * `time_frame` - The period to report on (defaults to 24 hours)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::MetricsTimeFrame};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let metrics = client
+    ///     .messaging_profiles()
+    ///     .metrics("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d", Some(MetricsTimeFrame::SevenDays))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn metrics(
+        &self,
+        id: &str,
+        time_frame: Option<MetricsTimeFrame>,
+    ) -> Result<ApiResponse<MessagingProfileMetrics>, TelnyxError> {
+        self.client
+            .get_with_query(
+                &format!("/messaging_profiles/{}/metrics", id),
+                &Query::new().param("time_frame", time_frame),
+                &self.options,
+            )
+            .await
+    }
+}
@@ -1,3 +1,17 @@
 mod address_endpoints;
+mod available_phone_number_endpoints;
+mod managed_account_endpoints;
+mod messaging_endpoints;
+mod messaging_profile_endpoints;
+mod number_order_endpoints;
+mod number_reservation_endpoints;
+mod phone_number_endpoints;
 
 pub use address_endpoints::*;
+pub use available_phone_number_endpoints::*;
+pub use managed_account_endpoints::*;
+pub use messaging_endpoints::*;
+pub use messaging_profile_endpoints::*;
+pub use number_order_endpoints::*;
+pub use number_reservation_endpoints::*;
+pub use phone_number_endpoints::*;
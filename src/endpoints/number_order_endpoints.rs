@@ -0,0 +1,216 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateNumberOrderRequest, ListNumberOrdersParams,
+        NumberOrder, SubNumberOrder, UpdateNumberOrderRequest,
+    },
+    pagination::Paginator,
+    query::ToQuery,
+    request_options::RequestOptions,
+};
+
+/// API client for number orders
+#[derive(Debug, Clone)]
+pub struct NumberOrderApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl NumberOrderApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    ///
+    /// An idempotency key makes the next order safe to retry without
+    /// ordering the same numbers twice. Later orders through this handle do
+    /// not reuse the key.
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// List number orders
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional pagination and filter parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{ListNumberOrdersParams, NumberOrderStatus}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let params = ListNumberOrdersParams::builder()
+    ///     .status(NumberOrderStatus::Pending)
+    ///     .build();
+    /// let orders = client.number_orders().list(Some(params)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        params: Option<ListNumberOrdersParams>,
+    ) -> Result<ApiListResponse<NumberOrder>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/number_orders",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all number orders, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter parameters. `page_number` sets the first
+    ///   page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let orders = client.number_orders().list_all(None).collect_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        params: Option<ListNumberOrdersParams>,
+    ) -> Paginator<'static, NumberOrder> {
+        self.client
+            .paginate("/number_orders", params.unwrap_or_default(), &self.options)
+    }
+
+    /// Get a number order by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The number order ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let order = client
+    ///     .number_orders()
+    ///     .get("12ade33a-21c0-473b-b055-b3c836e1c292")
+    ///     .await?;
+    /// println!("Order is {:?}", order.data.status);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.client
+            .get(&format!("/number_orders/{}", id), &self.options)
+            .await
+    }
+
+    /// Order phone numbers
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The numbers to order and their settings
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{CreateNumberOrderRequest, PhoneNumberOrderRequest}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = CreateNumberOrderRequest::builder()
+    ///     .phone_numbers(vec![
+    ///         PhoneNumberOrderRequest::builder()
+    ///             .phone_number("+13125550000")
+    ///             .build(),
+    ///     ])
+    ///     .connection_id("1494404757140276705")
+    ///     .customer_reference("customer-42")
+    ///     .build();
+    ///
+    /// let order = client.number_orders().create(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        request: CreateNumberOrderRequest,
+    ) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.client
+            .post("/number_orders", &request, &self.options)
+            .await
+    }
+
+    /// Update a number order, e.g. to fulfil its regulatory requirements
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The number order ID
+    /// * `request` - The fields to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{RegulatoryRequirement, UpdateNumberOrderRequest}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdateNumberOrderRequest::builder()
+    ///     .regulatory_requirements(vec![
+    ///         RegulatoryRequirement::builder()
+    ///             .requirement_id("8ffb3622-7c6b-4ccc-b65f-7a3dc0099576")
+    ///             .field_value("45f45a04-b4be-4592-95b1-9306b9db2b21")
+    ///             .build(),
+    ///     ])
+    ///     .build();
+    ///
+    /// let order = client
+    ///     .number_orders()
+    ///     .update("12ade33a-21c0-473b-b055-b3c836e1c292", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: &str,
+        request: UpdateNumberOrderRequest,
+    ) -> Result<ApiResponse<NumberOrder>, TelnyxError> {
+        self.client
+            .patch(&format!("/number_orders/{}", id), &request, &self.options)
+            .await
+    }
+
+    /// Get a sub number order by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The sub number order ID, as listed in a number order's `sub_number_orders_ids`
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let sub_order = client
+    ///     .number_orders()
+    ///     .get_sub_number_order("dc8e4d67-33a0-4629-9b6d-e5a86e8e8fd5")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sub_number_order(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<SubNumberOrder>, TelnyxError> {
+        self.client
+            .get(&format!("/sub_number_orders/{}", id), &self.options)
+            .await
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, CreateNumberReservationRequest, ListNumberReservationsParams,
+        NumberReservation,
+    },
+    pagination::Paginator,
+    query::ToQuery,
+    request_options::RequestOptions,
+};
+
+/// API client for number reservations
+///
+/// The Telnyx reservation endpoints do not accept the availability search
+/// filters: a reservation is created from an explicit list of numbers, and
+/// reservations are listed by status, creation time, number and customer
+/// reference only. To reserve numbers matching a
+/// [`PhoneNumberSearchFilter`](crate::models::PhoneNumberSearchFilter),
+/// search with [`AvailablePhoneNumberApi::search`](crate::endpoints::AvailablePhoneNumberApi::search)
+/// and pass the numbers you choose to [`NumberReservationApi::create`]. The
+/// crate does not combine the two calls, since other customers can reserve
+/// the numbers in between and a broad filter would reserve every result.
+///
+/// # Example
+///
+/// ```no_run
+/// # use telnyx_rs::{TelnyxClient, models::{CreateNumberReservationRequest, PhoneNumberReservationRequest, PhoneNumberSearchFilter}};
+/// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+/// let filter = PhoneNumberSearchFilter::builder()
+///     .country_code("US")
+///     .national_destination_code("312")
+///     .reservable(true)
+///     .best_effort(false)
+///     .limit(2)
+///     .build();
+/// let available = client.available_phone_numbers().search(Some(filter)).await?;
+///
+/// let request = CreateNumberReservationRequest::builder()
+///     .phone_numbers(
+///         available
+///             .data
+///             .into_iter()
+///             .map(|number| {
+///                 PhoneNumberReservationRequest::builder()
+///                     .phone_number(number.phone_number)
+///                     .build()
+///             })
+///             .collect(),
+///     )
+///     .build();
+/// let reservation = client.number_reservations().create(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NumberReservationApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl NumberReservationApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// List number reservations
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional pagination and filter parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{ListNumberReservationsParams, ReservationStatus}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let params = ListNumberReservationsParams::builder()
+    ///     .status(ReservationStatus::Success)
+    ///     .customer_reference("customer-42")
+    ///     .build();
+    /// let reservations = client.number_reservations().list(Some(params)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        params: Option<ListNumberReservationsParams>,
+    ) -> Result<ApiListResponse<NumberReservation>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/number_reservations",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all number reservations, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter parameters. `page_number` sets the first
+    ///   page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let reservations = client
+    ///     .number_reservations()
+    ///     .list_all(None)
+    ///     .collect_all()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        params: Option<ListNumberReservationsParams>,
+    ) -> Paginator<'static, NumberReservation> {
+        self.client.paginate(
+            "/number_reservations",
+            params.unwrap_or_default(),
+            &self.options,
+        )
+    }
+
+    /// Get a number reservation by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The number reservation ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let reservation = client
+    ///     .number_reservations()
+    ///     .get("12ade33a-21c0-473b-b055-b3c836e1c292")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.client
+            .get(&format!("/number_reservations/{}", id), &self.options)
+            .await
+    }
+
+    /// Reserve phone numbers
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The numbers to reserve
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{CreateNumberReservationRequest, PhoneNumberReservationRequest}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = CreateNumberReservationRequest::builder()
+    ///     .phone_numbers(vec![
+    ///         PhoneNumberReservationRequest::builder()
+    ///             .phone_number("+13125550000")
+    ///             .build(),
+    ///     ])
+    ///     .customer_reference("customer-42")
+    ///     .build();
+    ///
+    /// let reservation = client.number_reservations().create(request).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        request: CreateNumberReservationRequest,
+    ) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.client
+            .post("/number_reservations", &request, &self.options)
+            .await
+    }
+
+    /// Extend a number reservation before it expires
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The number reservation ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let reservation = client
+    ///     .number_reservations()
+    ///     .extend("12ade33a-21c0-473b-b055-b3c836e1c292")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn extend(&self, id: &str) -> Result<ApiResponse<NumberReservation>, TelnyxError> {
+        self.client
+            .post(
+                &format!("/number_reservations/{}/actions/extend", id),
+                &serde_json::Map::new(),
+                &self.options,
+            )
+            .await
+    }
+}
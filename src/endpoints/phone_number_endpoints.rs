@@ -0,0 +1,300 @@
+use crate::{
+    client::TelnyxClient,
+    error::TelnyxError,
+    models::{
+        ApiListResponse, ApiResponse, ListPhoneNumbersParams, MessagingPhoneNumber, PhoneNumber,
+        PhoneNumberVoiceSettings, UpdatePhoneNumberMessagingRequest, UpdatePhoneNumberRequest,
+        UpdatePhoneNumberVoiceSettingsRequest,
+    },
+    pagination::Paginator,
+    query::ToQuery,
+    request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// API client for the phone numbers owned by the account
+#[derive(Debug, Clone)]
+pub struct PhoneNumberApi {
+    client: TelnyxClient,
+    options: RequestOptions,
+}
+
+impl PhoneNumberApi {
+    pub(crate) fn new(client: TelnyxClient) -> Self {
+        Self {
+            client,
+            options: RequestOptions::default(),
+        }
+    }
+
+    /// Use `options` for every request made through this handle
+    pub fn with_options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// List phone numbers
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional pagination, filter and sort parameters
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{ListPhoneNumbersParams, PhoneNumberStatus}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let params = ListPhoneNumbersParams::builder()
+    ///     .tag("support")
+    ///     .status(PhoneNumberStatus::Active)
+    ///     .build();
+    /// let numbers = client.phone_numbers().list(Some(params)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(
+        &self,
+        params: Option<ListPhoneNumbersParams>,
+    ) -> Result<ApiListResponse<PhoneNumber>, TelnyxError> {
+        self.client
+            .get_with_query(
+                "/phone_numbers",
+                &params.unwrap_or_default().to_query(),
+                &self.options,
+            )
+            .await
+    }
+
+    /// Stream all phone numbers, fetching pages on demand
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - Optional filter and sort parameters. `page_number` sets
+    ///   the first page to fetch and `page_size` applies to every page.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let numbers = client.phone_numbers().list_all(None).collect_all().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_all(
+        &self,
+        params: Option<ListPhoneNumbersParams>,
+    ) -> Paginator<'static, PhoneNumber> {
+        self.client
+            .paginate("/phone_numbers", params.unwrap_or_default(), &self.options)
+    }
+
+    /// Get a phone number by ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID, or the number itself in +E.164 format
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let number = client.phone_numbers().get("1293384261075731499").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: &str) -> Result<ApiResponse<PhoneNumber>, TelnyxError> {
+        self.client
+            .get(&format!("/phone_numbers/{}", id), &self.options)
+            .await
+    }
+
+    /// Update a phone number
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID
+    /// * `request` - The fields to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::UpdatePhoneNumberRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdatePhoneNumberRequest::builder()
+    ///     .tags(vec!["support".into(), "east".into()])
+    ///     .connection_id("1494404757140276705")
+    ///     .build();
+    ///
+    /// let number = client
+    ///     .phone_numbers()
+    ///     .update("1293384261075731499", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberRequest,
+    ) -> Result<ApiResponse<PhoneNumber>, TelnyxError> {
+        self.client
+            .patch(&format!("/phone_numbers/{}", id), &request, &self.options)
+            .await
+    }
+
+    /// Delete a phone number, releasing it from the account
+    ///
+    /// Returns the response metadata, which carries the request ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID to delete
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// client.phone_numbers().delete("1293384261075731499").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, id: &str) -> Result<ResponseMeta, TelnyxError> {
+        self.client
+            .delete(&format!("/phone_numbers/{}", id), &self.options)
+            .await
+    }
+
+    /// Get the voice settings of a phone number
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let voice = client.phone_numbers().get_voice("1293384261075731499").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_voice(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<PhoneNumberVoiceSettings>, TelnyxError> {
+        self.client
+            .get(&format!("/phone_numbers/{}/voice", id), &self.options)
+            .await
+    }
+
+    /// Update the voice settings of a phone number
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID
+    /// * `request` - The settings to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::{CallForwarding, UpdatePhoneNumberVoiceSettingsRequest}};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdatePhoneNumberVoiceSettingsRequest::builder()
+    ///     .call_forwarding(
+    ///         CallForwarding::builder()
+    ///             .call_forwarding_enabled(true)
+    ///             .forwards_to("+13125550002")
+    ///             .forwarding_type("on-failure")
+    ///             .build(),
+    ///     )
+    ///     .build();
+    ///
+    /// let voice = client
+    ///     .phone_numbers()
+    ///     .update_voice("1293384261075731499", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_voice(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberVoiceSettingsRequest,
+    ) -> Result<ApiResponse<PhoneNumberVoiceSettings>, TelnyxError> {
+        self.client
+            .patch(
+                &format!("/phone_numbers/{}/voice", id),
+                &request,
+                &self.options,
+            )
+            .await
+    }
+
+    /// Get the messaging settings of a phone number
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::TelnyxClient;
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let messaging = client
+    ///     .phone_numbers()
+    ///     .get_messaging("1293384261075731499")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_messaging(
+        &self,
+        id: &str,
+    ) -> Result<ApiResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.client
+            .get(&format!("/phone_numbers/{}/messaging", id), &self.options)
+            .await
+    }
+
+    /// Update the messaging settings of a phone number
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The phone number ID
+    /// * `request` - The settings to update
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use telnyx_rs::{TelnyxClient, models::UpdatePhoneNumberMessagingRequest};
+    /// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+    /// let request = UpdatePhoneNumberMessagingRequest::builder()
+    ///     .messaging_profile_id("400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d")
+    ///     .build();
+    ///
+    /// let messaging = client
+    ///     .phone_numbers()
+    ///     .update_messaging("1293384261075731499", request)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_messaging(
+        &self,
+        id: &str,
+        request: UpdatePhoneNumberMessagingRequest,
+    ) -> Result<ApiResponse<MessagingPhoneNumber>, TelnyxError> {
+        self.client
+            .patch(
+                &format!("/phone_numbers/{}/messaging", id),
+                &request,
+                &self.options,
+            )
+            .await
+    }
+}
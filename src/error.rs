@@ -1,18 +1,147 @@
+use std::time::Duration;
 use thiserror::Error;
 
+use crate::models::{ApiError, ApiErrorResponse};
+
 /// Error type for all Tenlyx error types
 #[derive(Error, Debug)]
 pub enum TelnyxError {
-    /// HTTP request failed for timeout or transport issues
+    /// HTTP request failed for transport issues other than timeouts and
+    /// connection failures
     #[error("HTTP requestr failed: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(reqwest::Error),
+    /// HTTP request timed out
+    #[error("HTTP request timed out: {0}")]
+    Timeout(reqwest::Error),
+    /// Could not connect to the API
+    #[error("Connection failed: {0}")]
+    Connect(reqwest::Error),
     /// API error response was received
-    #[error("API error (status {status}): {message}")]
-    Api { status: u16, message: String },
+    #[error("API error (status {status}): {}", summarize(errors, message))]
+    Api {
+        /// HTTP status code of the response
+        status: u16,
+        /// Raw response body, kept as a fallback when `errors` is empty
+        message: String,
+        /// Errors parsed from the response body
+        errors: Vec<ApiError>,
+        /// The `x-request-id` header of the response, if present
+        request_id: Option<String>,
+    },
     /// Failed to parse (deserialize) API response
     #[error("Failed to parse response: {0}")]
     Parse(#[from] serde_json::Error),
     /// Client configuration error
     #[error("Configuration error: {0}")]
     Config(String),
+    /// The request could not be built from the given parameters
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    /// The circuit breaker is open and the request was not sent
+    #[error("Circuit breaker open, retry in {retry_after:?}")]
+    CircuitOpen {
+        /// Time until the circuit starts letting probe requests through
+        retry_after: Duration,
+    },
+}
+
+impl From<reqwest::Error> for TelnyxError {
+    fn from(error: reqwest::Error) -> Self {
+        if error.is_timeout() {
+            TelnyxError::Timeout(error)
+        } else if error.is_connect() {
+            TelnyxError::Connect(error)
+        } else {
+            TelnyxError::Http(error)
+        }
+    }
+}
+
+impl TelnyxError {
+    /// Build an [`TelnyxError::Api`] from an error response, parsing the
+    /// Telnyx `errors` array when the body contains one
+    pub(crate) fn api(status: u16, body: String, request_id: Option<String>) -> Self {
+        let errors = serde_json::from_str::<ApiErrorResponse>(&body)
+            .map(|response| response.errors)
+            .unwrap_or_default();
+
+        TelnyxError::Api {
+            status,
+            message: body,
+            errors,
+            request_id,
+        }
+    }
+
+    /// The HTTP status code for API errors
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            TelnyxError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Whether the request may succeed if sent again: timeouts, connection
+    /// failures, connections dropped while sending the request or reading
+    /// the response, rate limiting and server errors
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            TelnyxError::Timeout(_) | TelnyxError::Connect(_) => true,
+            TelnyxError::Http(error) => error.is_request() || error.is_body(),
+            TelnyxError::Api { status, .. } => *status == 429 || *status >= 500,
+            _ => false,
+        }
+    }
+
+    /// Whether the API rejected the request because of rate limiting (429)
+    pub fn is_rate_limited(&self) -> bool {
+        self.status() == Some(429)
+    }
+
+    /// Whether the API rejected the credentials (401 or 403)
+    pub fn is_auth(&self) -> bool {
+        matches!(self.status(), Some(401 | 403))
+    }
+
+    /// Whether the requested resource does not exist (404)
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404)
+    }
+
+    /// Whether the API rejected the request parameters (400 or 422)
+    pub fn is_validation(&self) -> bool {
+        matches!(self.status(), Some(400 | 422))
+    }
+
+    /// The `x-request-id` of the response for API errors
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            TelnyxError::Api { request_id, .. } => request_id.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// The errors reported by the API, empty for non-API errors or
+    /// unparseable bodies
+    pub fn api_errors(&self) -> &[ApiError] {
+        match self {
+            TelnyxError::Api { errors, .. } => errors,
+            _ => &[],
+        }
+    }
+}
+
+fn summarize(errors: &[ApiError], message: &str) -> String {
+    if errors.is_empty() {
+        return message.to_string();
+    }
+
+    errors
+        .iter()
+        .map(|error| match &error.detail {
+            Some(detail) => format!("{} ({}): {}", error.title, error.code, detail),
+            None => format!("{} ({})", error.title, error.code),
+        })
+        .collect::<Vec<_>>()
+        .join("; ")
 }
@@ -2,10 +2,37 @@
 #![forbid(clippy::expect_used)]
 #![forbid(clippy::panic)]
 
+#[cfg(feature = "blocking")]
+pub mod blocking;
+mod circuit_breaker;
 mod client;
+mod config;
 pub mod endpoints;
 mod error;
+mod middleware;
 pub mod models;
+mod pagination;
+pub mod query;
+mod rate_limiter;
+mod request;
+mod request_options;
+mod response;
+mod retry;
+#[cfg(feature = "tracing")]
+mod telemetry;
+mod transport;
 
-pub use client::{TelnyxClient, TelnyxClientBuilder};
+pub use circuit_breaker::{CircuitBreakerPolicy, CircuitState};
+pub use client::{HttpVersion, TelnyxClient, TelnyxClientBuilder};
+pub use config::{CONFIG_FILE_ENV, ClientConfig, PROFILE_ENV, RetryConfig};
 pub use error::TelnyxError;
+pub use middleware::{Middleware, Next};
+pub use pagination::Paginator;
+pub use rate_limiter::{Quota, RateLimitPolicy};
+pub use request::RequestBuilder;
+pub use request_options::RequestOptions;
+pub use response::{RateLimit, ResponseMeta};
+pub use retry::RetryPolicy;
+pub use transport::{HttpTransport, ReqwestTransport, TransportRequest, TransportResponse};
+
+pub use reqwest::{Method, StatusCode, Url, header};
@@ -0,0 +1,85 @@
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+use crate::{
+    error::TelnyxError,
+    transport::{HttpTransport, TransportRequest, TransportResponse},
+};
+
+/// Hook into every request sent by a [`TelnyxClient`](crate::TelnyxClient)
+///
+/// Middleware runs once per attempt, after the request is prepared and
+/// before it reaches the [`HttpTransport`], so retries pass through the
+/// chain again. Middleware registered first runs outermost. A middleware may
+/// modify the request, inspect the response, or return a response without
+/// calling [`Next::run`].
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::future::BoxFuture;
+/// use telnyx_rs::{
+///     Middleware, Next, TelnyxClient, TelnyxError, TransportRequest, TransportResponse,
+///     header::HeaderValue,
+/// };
+///
+/// struct TenantHeader(&'static str);
+///
+/// impl Middleware for TenantHeader {
+///     fn handle<'a>(
+///         &'a self,
+///         mut request: TransportRequest,
+///         next: Next<'a>,
+///     ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>> {
+///         request
+///             .headers
+///             .insert("x-tenant-id", HeaderValue::from_static(self.0));
+///         next.run(request)
+///     }
+/// }
+///
+/// # fn example() -> Result<(), TelnyxError> {
+/// let client = TelnyxClient::builder()
+///     .api_key("your-api-key")
+///     .middleware(TenantHeader("tenant-42"))
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Handle a request, usually by passing it on with `next.run(request)`
+    fn handle<'a>(
+        &'a self,
+        request: TransportRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>>;
+}
+
+/// The remainder of the middleware chain, ending at the transport
+pub struct Next<'a> {
+    middleware: &'a [Arc<dyn Middleware>],
+    transport: &'a dyn HttpTransport,
+}
+
+impl<'a> Next<'a> {
+    pub(crate) fn new(
+        middleware: &'a [Arc<dyn Middleware>],
+        transport: &'a dyn HttpTransport,
+    ) -> Self {
+        Self {
+            middleware,
+            transport,
+        }
+    }
+
+    /// Pass the request to the next middleware, or to the transport
+    pub fn run(
+        self,
+        request: TransportRequest,
+    ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>> {
+        match self.middleware.split_first() {
+            Some((current, rest)) => current.handle(request, Next::new(rest, self.transport)),
+            None => self.transport.send(request),
+        }
+    }
+}
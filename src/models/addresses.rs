@@ -2,6 +2,13 @@ use bon::Builder;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+use super::ApiError;
+
 /// Address list and detail object
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Address {
@@ -57,8 +64,104 @@ pub struct Address {
     pub updated_at: DateTime<Utc>,
 }
 
+/// Query parameters for listing addresses
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListAddressesParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by an exact customer reference.
+    pub customer_reference_eq: Option<String>,
+    /// Filter by a partial customer reference.
+    pub customer_reference_contains: Option<String>,
+    /// Filter by a partial street address.
+    pub street_address_contains: Option<String>,
+    /// Filter by the address book flag.
+    pub address_book_eq: Option<bool>,
+    /// Only return addresses that are (or are not) used as an emergency address.
+    pub used_as_emergency: Option<bool>,
+    /// The sort order of the results.
+    pub sort: Option<AddressSort>,
+}
+
+impl ToQuery for ListAddressesParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter_op(
+                "customer_reference",
+                "eq",
+                self.customer_reference_eq.as_ref(),
+            )
+            .filter_op(
+                "customer_reference",
+                "contains",
+                self.customer_reference_contains.as_ref(),
+            )
+            .filter_op(
+                "street_address",
+                "contains",
+                self.street_address_contains.as_ref(),
+            )
+            .filter_op("address_book", "eq", self.address_book_eq)
+            .filter("used_as_emergency", self.used_as_emergency)
+            .sort(self.sort)
+    }
+}
+
+impl Paginated for ListAddressesParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// Sort order for listing addresses
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum AddressSort {
+    /// Oldest first
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    /// Newest first
+    #[serde(rename = "-created_at")]
+    CreatedAtDesc,
+    /// First name, ascending
+    #[serde(rename = "first_name")]
+    FirstName,
+    /// First name, descending
+    #[serde(rename = "-first_name")]
+    FirstNameDesc,
+    /// Last name, ascending
+    #[serde(rename = "last_name")]
+    LastName,
+    /// Last name, descending
+    #[serde(rename = "-last_name")]
+    LastNameDesc,
+    /// Business name, ascending
+    #[serde(rename = "business_name")]
+    BusinessName,
+    /// Business name, descending
+    #[serde(rename = "-business_name")]
+    BusinessNameDesc,
+    /// Street address, ascending
+    #[serde(rename = "street_address")]
+    StreetAddress,
+    /// Street address, descending
+    #[serde(rename = "-street_address")]
+    StreetAddressDesc,
+}
+
 /// A request to create a new address
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
 pub struct CreateAddressRequest {
     /// A customer reference string for customer look ups.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -108,6 +211,7 @@ pub struct CreateAddressRequest {
 
 /// A request to validate an address for emergecy services
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
 pub struct ValidateAddressRequest {
     /// The primary street address information about the address.
     pub street_address: String,
@@ -164,30 +268,7 @@ pub struct ValidateAddressField {
 }
 
 /// Address API error object
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct AddressValidationError {
-    /// Error code
-    pub code: String,
-    /// Error title
-    pub title: String,
-    /// Detailed error description
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub detail: Option<String>,
-    /// Source location of the error
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub source: Option<ErrorSource>,
-}
-
-/// Source location of an API error
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ErrorSource {
-    /// Indicates which query parameter caused the error.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub parameter: Option<String>,
-    /// JSON pointer (RFC6901) to the offending entity.
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub pointer: Option<String>,
-}
+pub type AddressValidationError = ApiError;
 
 /// Indicates whether an address is valid or invalid, with an unknown fallback
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -205,6 +286,7 @@ pub enum AddressValidationStatus {
 
 /// Request to accept this address suggestion as the new emergency address
 #[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
 pub struct AddressAcceptSuggestionRequest {
     /// The ID of the address.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -0,0 +1,239 @@
+use bon::Builder;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    query::{Query, ToQuery},
+    response::{ResponseMeta, WithResponseMeta},
+};
+
+/// A phone number available for ordering or reservation
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AvailablePhoneNumber {
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The +E.164-formatted phone number.
+    pub phone_number: String,
+    /// The vanity format of the number, if it has one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vanity_format: Option<String>,
+    /// Whether the number only approximately matches the search filters.
+    #[serde(default)]
+    pub best_effort: bool,
+    /// Whether the number can be activated immediately after ordering.
+    #[serde(default)]
+    pub quickship: bool,
+    /// Whether the number can be reserved.
+    #[serde(default)]
+    pub reservable: bool,
+    /// The regions the number belongs to.
+    #[serde(default)]
+    pub region_information: Vec<RegionInformation>,
+    /// The cost of ordering and keeping the number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_information: Option<CostInformation>,
+    /// The features the number supports.
+    #[serde(default)]
+    pub features: Vec<AvailableFeature>,
+}
+
+/// A region an available phone number belongs to
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RegionInformation {
+    /// The kind of region, e.g. `country_code`, `rate_center` or `state`.
+    pub region_type: String,
+    /// The name of the region.
+    pub region_name: String,
+}
+
+/// The cost of an available phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct CostInformation {
+    /// The one-time cost of ordering the number.
+    pub upfront_cost: String,
+    /// The recurring monthly cost of the number.
+    pub monthly_cost: String,
+    /// The ISO 4217 currency identifier.
+    pub currency: String,
+}
+
+/// A feature supported by an available phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AvailableFeature {
+    /// The feature.
+    pub name: PhoneNumberFeature,
+}
+
+/// A phone number feature, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneNumberFeature {
+    /// Sending and receiving SMS
+    Sms,
+    /// Sending and receiving MMS
+    Mms,
+    /// Voice calls
+    Voice,
+    /// Fax
+    Fax,
+    /// Emergency calling
+    Emergency,
+    /// HD voice calls
+    HdVoice,
+    /// Sending SMS to international destinations
+    InternationalSms,
+    /// Local calling
+    LocalCalling,
+    /// Fallback for features not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of a phone number, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PhoneNumberType {
+    /// A local (geographic) number
+    Local,
+    /// A toll-free number
+    TollFree,
+    /// A mobile number
+    Mobile,
+    /// A non-geographic national number
+    National,
+    /// A shared cost number
+    SharedCost,
+    /// Fallback for types not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Filters for searching phone number inventory
+///
+/// Used to search for available numbers to order.
+/// To search by NPA-NXX, set `national_destination_code` to the NPA and
+/// `phone_number_starts_with` to the NPA-NXX digits.
+///
+/// # Example
+///
+/// ```
+/// use telnyx_rs::models::{PhoneNumberFeature, PhoneNumberSearchFilter, PhoneNumberType};
+///
+/// let filter = PhoneNumberSearchFilter::builder()
+///     .country_code("US")
+///     .administrative_area("IL")
+///     .national_destination_code("312")
+///     .features(vec![PhoneNumberFeature::Sms, PhoneNumberFeature::Voice])
+///     .phone_number_type(PhoneNumberType::Local)
+///     .limit(10)
+///     .build();
+/// ```
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct PhoneNumberSearchFilter {
+    /// Filter by ISO 3166-1 alpha-2 country code.
+    pub country_code: Option<String>,
+    /// Filter by city or locality.
+    pub locality: Option<String>,
+    /// Filter by state or province, e.g. `IL`.
+    pub administrative_area: Option<String>,
+    /// Filter by the national destination code, the NPA (area code) in North America.
+    pub national_destination_code: Option<String>,
+    /// Filter by rate center. Requires `country_code` and `administrative_area`.
+    pub rate_center: Option<String>,
+    /// Filter numbers starting with these digits, excluding the country code.
+    pub phone_number_starts_with: Option<String>,
+    /// Filter numbers ending with these digits.
+    pub phone_number_ends_with: Option<String>,
+    /// Filter numbers containing these digits.
+    pub phone_number_contains: Option<String>,
+    /// Filter by features the numbers must all support.
+    #[builder(default)]
+    pub features: Vec<PhoneNumberFeature>,
+    /// Filter by number type.
+    pub phone_number_type: Option<PhoneNumberType>,
+    /// Include numbers that only approximately match the filters.
+    pub best_effort: Option<bool>,
+    /// Filter numbers that can be activated immediately after ordering.
+    pub quickship: Option<bool>,
+    /// Filter numbers that can be reserved.
+    pub reservable: Option<bool>,
+    /// Exclude numbers held by other customers.
+    pub exclude_held_numbers: Option<bool>,
+    /// The maximum number of results.
+    pub limit: Option<i32>,
+}
+
+impl ToQuery for PhoneNumberSearchFilter {
+    fn to_query(&self) -> Query {
+        let query = Query::new()
+            .filter("country_code", self.country_code.as_ref())
+            .filter("locality", self.locality.as_ref())
+            .filter("administrative_area", self.administrative_area.as_ref())
+            .filter(
+                "national_destination_code",
+                self.national_destination_code.as_ref(),
+            )
+            .filter("rate_center", self.rate_center.as_ref())
+            .filter_op(
+                "phone_number",
+                "starts_with",
+                self.phone_number_starts_with.as_ref(),
+            )
+            .filter_op(
+                "phone_number",
+                "ends_with",
+                self.phone_number_ends_with.as_ref(),
+            )
+            .filter_op(
+                "phone_number",
+                "contains",
+                self.phone_number_contains.as_ref(),
+            );
+
+        self.features
+            .iter()
+            .fold(query, |query, feature| {
+                query.param("filter[features][]", Some(feature))
+            })
+            .filter("phone_number_type", self.phone_number_type)
+            .filter("best_effort", self.best_effort)
+            .filter("quickship", self.quickship)
+            .filter("reservable", self.reservable)
+            .filter("exclude_held_numbers", self.exclude_held_numbers)
+            .filter("limit", self.limit)
+    }
+}
+
+/// Response envelope of an available phone number search
+///
+/// Search results are not paginated, so the metadata reports result counts
+/// instead of pages.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AvailablePhoneNumbersResponse {
+    /// The available phone numbers
+    pub data: Vec<AvailablePhoneNumber>,
+    /// Result counts for the search
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub meta: Option<AvailablePhoneNumbersMeta>,
+    /// Metadata about the HTTP response, set when returned by the client
+    #[serde(skip)]
+    pub response_meta: Option<ResponseMeta>,
+}
+
+impl WithResponseMeta for AvailablePhoneNumbersResponse {
+    fn set_response_meta(&mut self, meta: ResponseMeta) {
+        self.response_meta = Some(meta);
+    }
+}
+
+/// Result counts of an available phone number search
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AvailablePhoneNumbersMeta {
+    /// The number of results returned
+    pub total_results: i32,
+    /// How many of the results only approximately match the filters
+    #[serde(default)]
+    pub best_effort_results: i32,
+}
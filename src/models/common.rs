@@ -1,10 +1,19 @@
+use bon::Builder;
 use serde::{Deserialize, Serialize};
 
+use crate::{
+    query::{Query, ToQuery},
+    response::{ResponseMeta, WithResponseMeta},
+};
+
 /// API response envelope for non-list endpoints
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ApiResponse<T> {
     /// Endpoint specific response object
     pub data: T,
+    /// Metadata about the HTTP response, set when returned by the client
+    #[serde(skip)]
+    pub response_meta: Option<ResponseMeta>,
 }
 
 /// API response envelope for list endpoints
@@ -15,6 +24,21 @@ pub struct ApiListResponse<T> {
     /// Metadata for paginated responses
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub meta: Option<PaginationMeta>,
+    /// Metadata about the HTTP response, set when returned by the client
+    #[serde(skip)]
+    pub response_meta: Option<ResponseMeta>,
+}
+
+impl<T> WithResponseMeta for ApiResponse<T> {
+    fn set_response_meta(&mut self, meta: ResponseMeta) {
+        self.response_meta = Some(meta);
+    }
+}
+
+impl<T> WithResponseMeta for ApiListResponse<T> {
+    fn set_response_meta(&mut self, meta: ResponseMeta) {
+        self.response_meta = Some(meta);
+    }
 }
 
 /// Metadata for paginated responses from list endpoints
@@ -29,3 +53,57 @@ pub struct PaginationMeta {
     /// The total size of the page
     pub page_size: i32,
 }
+
+/// Pagination parameters for list endpoints without filters
+#[derive(Debug, Clone, Default, Builder)]
+pub struct PageParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+}
+
+impl ToQuery for PageParams {
+    fn to_query(&self) -> Query {
+        Query::new().page(self.page_number, self.page_size)
+    }
+}
+
+/// API error envelope returned with non-2xx responses
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ApiErrorResponse {
+    /// The errors reported by the API
+    #[serde(default)]
+    pub errors: Vec<ApiError>,
+}
+
+/// API error object
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApiError {
+    /// Error code
+    pub code: String,
+    /// Error title
+    pub title: String,
+    /// Detailed error description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// Source location of the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<ErrorSource>,
+}
+
+/// Source location of an API error
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ErrorSource {
+    /// Indicates which query parameter caused the error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<String>,
+    /// JSON pointer (RFC6901) to the offending entity.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pointer: Option<String>,
+}
+
+/// Hide a secret in `Debug` output, keeping whether it is set
+pub(crate) fn redacted(secret: Option<&str>) -> Option<&'static str> {
+    secret.map(|_| "[REDACTED]")
+}
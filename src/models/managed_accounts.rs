@@ -0,0 +1,232 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::common::redacted,
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+/// A managed account belonging to a manager account
+///
+/// The `Debug` output redacts the account's credentials.
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct ManagedAccount {
+    /// Uniquely identifies the managed account.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The managed account's email.
+    pub email: String,
+    /// The ID of the manager account associated with the managed account.
+    pub manager_account_id: String,
+    /// The organization the managed account is associated with.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_name: Option<String>,
+    /// The managed account's V2 API key.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_key: Option<String>,
+    /// The managed account's V1 API user.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_user: Option<String>,
+    /// The managed account's V1 API token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token: Option<String>,
+    /// Whether the managed account may have custom pricing configured.
+    #[serde(default)]
+    pub managed_account_allow_custom_pricing: bool,
+    /// Whether the managed account's charges are billed to the manager account.
+    #[serde(default)]
+    pub rollup_billing: bool,
+    /// The managed account's balance, if visible to the manager.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub balance: Option<ManagedAccountBalance>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl std::fmt::Debug for ManagedAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ManagedAccount")
+            .field("id", &self.id)
+            .field("record_type", &self.record_type)
+            .field("email", &self.email)
+            .field("manager_account_id", &self.manager_account_id)
+            .field("organization_name", &self.organization_name)
+            .field("api_key", &redacted(self.api_key.as_deref()))
+            .field("api_user", &redacted(self.api_user.as_deref()))
+            .field("api_token", &redacted(self.api_token.as_deref()))
+            .field(
+                "managed_account_allow_custom_pricing",
+                &self.managed_account_allow_custom_pricing,
+            )
+            .field("rollup_billing", &self.rollup_billing)
+            .field("balance", &self.balance)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+/// The balance of a managed account
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ManagedAccountBalance {
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// The account's current balance.
+    pub balance: String,
+    /// The account's credit limit.
+    pub credit_limit: String,
+    /// Available amount to spend (balance + credit limit).
+    pub available_credit: String,
+    /// The ISO 4217 currency identifier.
+    pub currency: String,
+}
+
+/// Query parameters for listing managed accounts
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListManagedAccountsParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by an exact email.
+    pub email_eq: Option<String>,
+    /// Filter by a partial email.
+    pub email_contains: Option<String>,
+    /// Filter by an exact organization name.
+    pub organization_name_eq: Option<String>,
+    /// Filter by a partial organization name.
+    pub organization_name_contains: Option<String>,
+    /// Include cancelled managed accounts in the results.
+    pub include_cancelled_accounts: Option<bool>,
+    /// The sort order of the results.
+    pub sort: Option<ManagedAccountSort>,
+}
+
+impl ToQuery for ListManagedAccountsParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter_op("email", "eq", self.email_eq.as_ref())
+            .filter_op("email", "contains", self.email_contains.as_ref())
+            .filter_op(
+                "organization_name",
+                "eq",
+                self.organization_name_eq.as_ref(),
+            )
+            .filter_op(
+                "organization_name",
+                "contains",
+                self.organization_name_contains.as_ref(),
+            )
+            .param(
+                "include_cancelled_accounts",
+                self.include_cancelled_accounts,
+            )
+            .sort(self.sort)
+    }
+}
+
+impl Paginated for ListManagedAccountsParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// Sort order for listing managed accounts
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ManagedAccountSort {
+    /// Oldest first
+    #[serde(rename = "created_at")]
+    CreatedAt,
+    /// Newest first
+    #[serde(rename = "-created_at")]
+    CreatedAtDesc,
+    /// Email, ascending
+    #[serde(rename = "email")]
+    Email,
+    /// Email, descending
+    #[serde(rename = "-email")]
+    EmailDesc,
+}
+
+/// A request to create a new managed account
+///
+/// The `Debug` output redacts the password.
+#[derive(Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CreateManagedAccountRequest {
+    /// The name of the business for which the new managed account is being created.
+    pub business_name: String,
+    /// The email address for the managed account. Generated when not provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Password for the managed account. Generated when not provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub password: Option<String>,
+    /// Whether the managed account may have custom pricing configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub managed_account_allow_custom_pricing: Option<bool>,
+    /// Whether the managed account's charges are billed to the manager account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup_billing: Option<bool>,
+}
+
+impl std::fmt::Debug for CreateManagedAccountRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateManagedAccountRequest")
+            .field("business_name", &self.business_name)
+            .field("email", &self.email)
+            .field("password", &redacted(self.password.as_deref()))
+            .field(
+                "managed_account_allow_custom_pricing",
+                &self.managed_account_allow_custom_pricing,
+            )
+            .field("rollup_billing", &self.rollup_billing)
+            .finish()
+    }
+}
+
+/// A request to update a managed account
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct UpdateManagedAccountRequest {
+    /// Whether the managed account may have custom pricing configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub managed_account_allow_custom_pricing: Option<bool>,
+}
+
+/// A request to enable a disabled managed account
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct EnableManagedAccountRequest {
+    /// Also re-enable the connections that were disabled along with the account.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reenable_all_connections: Option<bool>,
+}
+
+/// Global outbound channels that can still be allocated to managed accounts
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct AllocatableGlobalOutboundChannels {
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// The number of channels that can still be allocated to managed accounts.
+    pub allocatable_global_outbound_channels: i64,
+    /// The number of channels allocated to all managed accounts.
+    pub total_global_channels_allocated: i64,
+    /// Whether managed accounts may have custom pricing configured.
+    #[serde(default)]
+    pub managed_account_allow_custom_pricing: bool,
+}
@@ -0,0 +1,344 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::ApiError;
+
+/// A sent or received SMS or MMS message
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Message {
+    /// Uniquely identifies the message.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The direction of the message.
+    #[serde(default)]
+    pub direction: MessageDirection,
+    /// The type of message.
+    #[serde(rename = "type", default)]
+    pub message_type: MessageType,
+    /// Unique identifier for a messaging profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// The ID of the organization that owns the message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization_id: Option<String>,
+    /// The sending address.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub from: Option<MessageEndpoint>,
+    /// The recipients of the message and their delivery status.
+    #[serde(default)]
+    pub to: Vec<MessageRecipient>,
+    /// Recipients copied on a group MMS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<MessageRecipient>,
+    /// Message body (i.e., content) as a non-empty string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Subject of multimedia message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// Media attached to the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub media: Vec<MessageMedia>,
+    /// The URL where webhooks related to this message will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this message will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// Encoding scheme used for the message body.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// Number of parts into which the message's body must be split.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parts: Option<i32>,
+    /// Tags associated with the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// The cost of the message, once known.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost: Option<MessageCost>,
+    /// ISO 8601 formatted date indicating when the message request was received.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub received_at: Option<DateTime<Utc>>,
+    /// ISO 8601 formatted date indicating when the message was sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sent_at: Option<DateTime<Utc>>,
+    /// ISO 8601 formatted date indicating when the message was finalized.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Message must be out of the queue by this time or else it will be discarded and marked as 'sending_failed'.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_until: Option<DateTime<Utc>>,
+    /// Errors reported while sending or delivering the message.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ApiError>,
+}
+
+/// The sending side of a message
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessageEndpoint {
+    /// Sending address (+E.164 formatted phone number, alphanumeric sender ID, or short code).
+    pub phone_number: String,
+    /// The carrier of the sender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+    /// The line type of the sender.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_type: Option<String>,
+}
+
+/// A recipient of a message
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessageRecipient {
+    /// Receiving address (+E.164 formatted phone number or short code).
+    pub phone_number: String,
+    /// The delivery status for this recipient.
+    #[serde(default)]
+    pub status: MessageStatus,
+    /// The carrier of the receiver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub carrier: Option<String>,
+    /// The line type of the receiver.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_type: Option<String>,
+}
+
+/// Media attached to a message
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessageMedia {
+    /// The url of the media requested to be sent.
+    pub url: String,
+    /// The MIME type of the requested media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// The SHA256 hash of the requested media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+    /// The size of the requested media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<i64>,
+}
+
+/// The cost of a message
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessageCost {
+    /// The amount deducted from your account.
+    pub amount: String,
+    /// The ISO 4217 currency identifier.
+    pub currency: String,
+}
+
+/// The direction of a message, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MessageDirection {
+    /// Sent from a Telnyx number
+    Outbound,
+    /// Received by a Telnyx number
+    Inbound,
+    /// Fallback for directions not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The type of a message, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MessageType {
+    /// Text message
+    #[serde(rename = "SMS")]
+    Sms,
+    /// Multimedia message
+    #[serde(rename = "MMS")]
+    Mms,
+    /// Fallback for types not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// The delivery status of a message, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageStatus {
+    /// Accepted and waiting to be sent
+    Queued,
+    /// Being sent to the carrier
+    Sending,
+    /// Handed to the carrier
+    Sent,
+    /// Not sent before its `valid_until` time
+    Expired,
+    /// Could not be sent to the carrier
+    SendingFailed,
+    /// The carrier did not confirm delivery
+    DeliveryUnconfirmed,
+    /// Delivered to the recipient
+    Delivered,
+    /// The carrier could not deliver the message
+    DeliveryFailed,
+    /// Inbound message received by Telnyx
+    Received,
+    /// Inbound message delivered to the webhook
+    WebhookDelivered,
+    /// Fallback for statuses not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl MessageStatus {
+    /// Whether the status is final and will not change
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            MessageStatus::Expired
+                | MessageStatus::SendingFailed
+                | MessageStatus::DeliveryUnconfirmed
+                | MessageStatus::Delivered
+                | MessageStatus::DeliveryFailed
+                | MessageStatus::WebhookDelivered
+        )
+    }
+}
+
+/// A request to send a message from a phone number, alphanumeric sender ID
+/// or short code
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct SendMessageRequest {
+    /// Sending address (+E.164 formatted phone number, alphanumeric sender ID, or short code).
+    pub from: String,
+    /// Receiving address (+E.164 formatted phone number or short code).
+    pub to: String,
+    /// Message body (i.e., content) as a non-empty string. Required for SMS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Subject of multimedia message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// A list of media URLs. The total media size must be less than 1 MB. Required for MMS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub media_urls: Vec<String>,
+    /// Unique identifier for a messaging profile. Required when sending from an alphanumeric sender ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// The URL where webhooks related to this message will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this message will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// If true, use the messaging profile's webhook settings and ignore the webhook URLs in the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_profile_webhooks: Option<bool>,
+    /// The protocol for sending the message, either SMS or MMS.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<MessageType>,
+    /// Automatically detect if an SMS message is unusually long and exceeds a recommended limit of message parts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_detect: Option<bool>,
+}
+
+/// A request to send a message from a number in a messaging profile's
+/// number pool
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct SendNumberPoolMessageRequest {
+    /// Unique identifier for a messaging profile with a number pool.
+    pub messaging_profile_id: String,
+    /// Receiving address (+E.164 formatted phone number or short code).
+    pub to: String,
+    /// Message body (i.e., content) as a non-empty string. Required for SMS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Subject of multimedia message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// A list of media URLs. The total media size must be less than 1 MB. Required for MMS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub media_urls: Vec<String>,
+    /// The URL where webhooks related to this message will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this message will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// If true, use the messaging profile's webhook settings and ignore the webhook URLs in the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_profile_webhooks: Option<bool>,
+    /// The protocol for sending the message, either SMS or MMS.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<MessageType>,
+    /// Automatically detect if an SMS message is unusually long and exceeds a recommended limit of message parts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_detect: Option<bool>,
+}
+
+/// A request to send a message from a short code
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct SendShortCodeMessageRequest {
+    /// Sending short code.
+    pub from: String,
+    /// Receiving address (+E.164 formatted phone number or short code).
+    pub to: String,
+    /// Message body (i.e., content) as a non-empty string. Required for SMS.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Subject of multimedia message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// A list of media URLs. The total media size must be less than 1 MB. Required for MMS.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub media_urls: Vec<String>,
+    /// The URL where webhooks related to this message will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this message will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// If true, use the messaging profile's webhook settings and ignore the webhook URLs in the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_profile_webhooks: Option<bool>,
+    /// The protocol for sending the message, either SMS or MMS.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub message_type: Option<MessageType>,
+    /// Automatically detect if an SMS message is unusually long and exceeds a recommended limit of message parts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_detect: Option<bool>,
+}
+
+/// A request to send an MMS to a group of recipients
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct SendGroupMmsRequest {
+    /// Sending address (+E.164 formatted phone number).
+    pub from: String,
+    /// Receiving addresses (+E.164 formatted phone numbers), up to 8.
+    pub to: Vec<String>,
+    /// Message body (i.e., content) as a non-empty string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    /// Subject of multimedia message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    /// A list of media URLs. The total media size must be less than 1 MB.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    #[builder(default)]
+    pub media_urls: Vec<String>,
+    /// The URL where webhooks related to this message will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this message will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// If true, use the messaging profile's webhook settings and ignore the webhook URLs in the request.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_profile_webhooks: Option<bool>,
+}
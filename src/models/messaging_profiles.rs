@@ -0,0 +1,401 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::common::redacted,
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+/// A messaging profile, which groups phone numbers and their messaging settings
+///
+/// The `Debug` output redacts the v1 secret.
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct MessagingProfile {
+    /// Uniquely identifies the messaging profile.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// A user friendly name for the messaging profile.
+    pub name: String,
+    /// Specifies whether the messaging profile is enabled or not.
+    #[serde(default)]
+    pub enabled: bool,
+    /// The URL where webhooks related to this messaging profile will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this messaging profile will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// Determines which webhook format will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_api_version: Option<WebhookApiVersion>,
+    /// Destinations to which the messaging profile is allowed to send, as ISO 3166-1 alpha-2 country codes. `*` allows all destinations.
+    #[serde(default)]
+    pub whitelisted_destinations: Vec<String>,
+    /// Number pool configuration, if the number pool is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_pool_settings: Option<NumberPoolSettings>,
+    /// URL shortener configuration, if URL shortening is enabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_shortener_settings: Option<UrlShortenerSettings>,
+    /// The alphanumeric sender ID to use when sending to destinations that require an alphanumeric sender ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha_sender: Option<String>,
+    /// The maximum amount of money (in USD) that can be spent by this profile before midnight UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_spend_limit: Option<String>,
+    /// Whether to enforce the value configured by `daily_spend_limit`.
+    #[serde(default)]
+    pub daily_spend_limit_enabled: bool,
+    /// Enables SMS fallback for MMS messages.
+    #[serde(default)]
+    pub mms_fall_back_to_sms: bool,
+    /// Enables automated resizing of MMS media.
+    #[serde(default)]
+    pub mms_transcoding: bool,
+    /// Secret used to authenticate with v1 endpoints.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub v1_secret: Option<String>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+impl std::fmt::Debug for MessagingProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MessagingProfile")
+            .field("id", &self.id)
+            .field("record_type", &self.record_type)
+            .field("name", &self.name)
+            .field("enabled", &self.enabled)
+            .field("webhook_url", &self.webhook_url)
+            .field("webhook_failover_url", &self.webhook_failover_url)
+            .field("webhook_api_version", &self.webhook_api_version)
+            .field("whitelisted_destinations", &self.whitelisted_destinations)
+            .field("number_pool_settings", &self.number_pool_settings)
+            .field("url_shortener_settings", &self.url_shortener_settings)
+            .field("alpha_sender", &self.alpha_sender)
+            .field("daily_spend_limit", &self.daily_spend_limit)
+            .field("daily_spend_limit_enabled", &self.daily_spend_limit_enabled)
+            .field("mms_fall_back_to_sms", &self.mms_fall_back_to_sms)
+            .field("mms_transcoding", &self.mms_transcoding)
+            .field("v1_secret", &redacted(self.v1_secret.as_deref()))
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+/// Webhook format sent for a messaging profile, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum WebhookApiVersion {
+    /// API v1 format
+    #[serde(rename = "1")]
+    V1,
+    /// API v2 format
+    #[serde(rename = "2")]
+    V2,
+    /// Legacy format compatible with other providers
+    #[serde(rename = "2010-04-01")]
+    V2010_04_01,
+    /// Fallback for versions not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Number pool configuration of a messaging profile
+///
+/// The weights set the relative likelihood of sending from a toll-free or
+/// a long code number.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct NumberPoolSettings {
+    /// Relative weight of toll-free numbers.
+    pub toll_free_weight: f64,
+    /// Relative weight of long code numbers.
+    pub long_code_weight: f64,
+    /// If true, an unhealthy number is skipped when selecting a sender.
+    #[serde(default)]
+    #[builder(default)]
+    pub skip_unhealthy: bool,
+    /// If true, keep sending to a recipient from the same number.
+    #[serde(default)]
+    #[builder(default)]
+    pub sticky_sender: bool,
+    /// If true, prefer numbers in the same area code as the recipient.
+    #[serde(default)]
+    #[builder(default)]
+    pub geomatch: bool,
+}
+
+/// URL shortener configuration of a messaging profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UrlShortenerSettings {
+    /// One of the domains provided by the Telnyx URL shortener service.
+    pub domain: String,
+    /// Optional prefix that can be used to identify your brand, and will appear in the Telnyx generated URLs after the domain name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prefix: Option<String>,
+    /// Use URL shortener service only if the link is on the list of links known to be blocked.
+    #[serde(default)]
+    #[builder(default)]
+    pub replace_blacklist_only: bool,
+    /// Receive webhooks for when your replaced links are clicked.
+    #[serde(default)]
+    #[builder(default)]
+    pub send_webhooks: bool,
+}
+
+/// Query parameters for listing messaging profiles
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListMessagingProfilesParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by an exact profile name.
+    pub name: Option<String>,
+}
+
+impl ToQuery for ListMessagingProfilesParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter("name", self.name.as_ref())
+    }
+}
+
+impl Paginated for ListMessagingProfilesParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// A request to create a new messaging profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CreateMessagingProfileRequest {
+    /// A user friendly name for the messaging profile.
+    pub name: String,
+    /// Destinations to which the messaging profile is allowed to send, as ISO 3166-1 alpha-2 country codes. `*` allows all destinations.
+    pub whitelisted_destinations: Vec<String>,
+    /// Specifies whether the messaging profile is enabled or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The URL where webhooks related to this messaging profile will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this messaging profile will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// Determines which webhook format will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_api_version: Option<WebhookApiVersion>,
+    /// Number pool configuration. Leave unset to disable the number pool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_pool_settings: Option<NumberPoolSettings>,
+    /// URL shortener configuration. Leave unset to disable URL shortening.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_shortener_settings: Option<UrlShortenerSettings>,
+    /// The alphanumeric sender ID to use when sending to destinations that require an alphanumeric sender ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha_sender: Option<String>,
+    /// The maximum amount of money (in USD) that can be spent by this profile before midnight UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_spend_limit: Option<String>,
+    /// Whether to enforce the value configured by `daily_spend_limit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_spend_limit_enabled: Option<bool>,
+    /// Enables SMS fallback for MMS messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mms_fall_back_to_sms: Option<bool>,
+    /// Enables automated resizing of MMS media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mms_transcoding: Option<bool>,
+}
+
+/// A request to update a messaging profile
+///
+/// Only the fields that are set are changed.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UpdateMessagingProfileRequest {
+    /// A user friendly name for the messaging profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Destinations to which the messaging profile is allowed to send, as ISO 3166-1 alpha-2 country codes. `*` allows all destinations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub whitelisted_destinations: Option<Vec<String>>,
+    /// Specifies whether the messaging profile is enabled or not.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// The URL where webhooks related to this messaging profile will be sent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_url: Option<String>,
+    /// The failover URL where webhooks related to this messaging profile will be sent if sending to the primary URL fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_failover_url: Option<String>,
+    /// Determines which webhook format will be used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub webhook_api_version: Option<WebhookApiVersion>,
+    /// Number pool configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_pool_settings: Option<NumberPoolSettings>,
+    /// URL shortener configuration.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url_shortener_settings: Option<UrlShortenerSettings>,
+    /// The alphanumeric sender ID to use when sending to destinations that require an alphanumeric sender ID.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alpha_sender: Option<String>,
+    /// The maximum amount of money (in USD) that can be spent by this profile before midnight UTC.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_spend_limit: Option<String>,
+    /// Whether to enforce the value configured by `daily_spend_limit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_spend_limit_enabled: Option<bool>,
+    /// Enables SMS fallback for MMS messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mms_fall_back_to_sms: Option<bool>,
+    /// Enables automated resizing of MMS media.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mms_transcoding: Option<bool>,
+}
+
+/// A phone number assigned to a messaging profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessagingPhoneNumber {
+    /// Uniquely identifies the phone number.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The +E.164-formatted phone number associated with this record.
+    pub phone_number: String,
+    /// Unique identifier for a messaging profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// ISO 3166-1 alpha-2 country code.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub country_code: Option<String>,
+    /// The type of the phone number, e.g. `long-code` or `toll-free`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub number_type: Option<String>,
+    /// The messaging product that the number is registered to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_product: Option<String>,
+    /// The messaging products that this number can be registered to use.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eligible_messaging_products: Vec<String>,
+    /// The messaging traffic or use case for which the number is currently configured.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub traffic_type: Option<String>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A short code assigned to a messaging profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ShortCode {
+    /// Uniquely identifies the short code.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// Short digit sequence used to address messages.
+    pub short_code: String,
+    /// ISO 3166-1 alpha-2 country code.
+    pub country_code: String,
+    /// Unique identifier for a messaging profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Time frame of messaging profile metrics
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum MetricsTimeFrame {
+    /// The last hour
+    #[serde(rename = "1h")]
+    OneHour,
+    /// The last 3 hours
+    #[serde(rename = "3h")]
+    ThreeHours,
+    /// The last 24 hours
+    #[default]
+    #[serde(rename = "24h")]
+    OneDay,
+    /// The last 3 days
+    #[serde(rename = "3d")]
+    ThreeDays,
+    /// The last 7 days
+    #[serde(rename = "7d")]
+    SevenDays,
+    /// The last 30 days
+    #[serde(rename = "30d")]
+    ThirtyDays,
+}
+
+/// Message metrics of a messaging profile
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessagingProfileMetrics {
+    /// Totals over the requested time frame.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub overview: Option<MessagingProfileMetricsOverview>,
+    /// Time series by metric type, in the format returned by the API.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub detailed: Vec<serde_json::Value>,
+}
+
+/// Totals of messaging profile metrics
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct MessagingProfileMetricsOverview {
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// The number of phone numbers associated with the messaging profile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_numbers: Option<i64>,
+    /// Outbound message totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outbound: Option<OutboundMessageMetrics>,
+    /// Inbound message totals.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound: Option<InboundMessageMetrics>,
+}
+
+/// Outbound message totals
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OutboundMessageMetrics {
+    /// The number of outbound messages sent.
+    #[serde(default)]
+    pub sent: i64,
+    /// The number of outbound messages delivered.
+    #[serde(default)]
+    pub delivered: i64,
+    /// The ratio of messages that failed to be delivered.
+    #[serde(default)]
+    pub error_ratio: f64,
+}
+
+/// Inbound message totals
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct InboundMessageMetrics {
+    /// The number of inbound messages received.
+    #[serde(default)]
+    pub received: i64,
+}
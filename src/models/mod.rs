@@ -1,5 +1,19 @@
 mod addresses;
+mod available_phone_numbers;
 mod common;
+mod managed_accounts;
+mod messages;
+mod messaging_profiles;
+mod number_orders;
+mod number_reservations;
+mod phone_numbers;
 
 pub use addresses::*;
+pub use available_phone_numbers::*;
 pub use common::*;
+pub use managed_accounts::*;
+pub use messages::*;
+pub use messaging_profiles::*;
+pub use number_orders::*;
+pub use number_reservations::*;
+pub use phone_numbers::*;
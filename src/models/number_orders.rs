@@ -0,0 +1,252 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::PhoneNumberType,
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+/// An order for one or more phone numbers
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NumberOrder {
+    /// Uniquely identifies the number order.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The phone numbers in the order.
+    #[serde(default)]
+    pub phone_numbers: Vec<NumberOrderPhoneNumber>,
+    /// The number of phone numbers in the order.
+    #[serde(default)]
+    pub phone_numbers_count: i32,
+    /// The status of the order.
+    #[serde(default)]
+    pub status: NumberOrderStatus,
+    /// Identifies the connection associated with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// Identifies the messaging profile associated with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// Identifies the billing group associated with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_group_id: Option<String>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// The IDs of the sub number orders the order was split into, one per country and number type.
+    #[serde(default)]
+    pub sub_number_orders_ids: Vec<String>,
+    /// True if all requirements are met for every phone number in the order.
+    #[serde(default)]
+    pub requirements_met: bool,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A phone number in a number order
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NumberOrderPhoneNumber {
+    /// Uniquely identifies the phone number in the order.
+    pub id: String,
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// The +E.164-formatted phone number.
+    pub phone_number: String,
+    /// The order status of the phone number.
+    #[serde(default)]
+    pub status: NumberOrderStatus,
+    /// True if all requirements are met for the phone number.
+    #[serde(default)]
+    pub requirements_met: bool,
+    /// The regulatory requirements of the phone number.
+    #[serde(default)]
+    pub regulatory_requirements: Vec<RegulatoryRequirement>,
+}
+
+/// Status of a number order or one of its phone numbers, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum NumberOrderStatus {
+    /// The order is being processed
+    Pending,
+    /// The order completed
+    Success,
+    /// The order failed
+    Failure,
+    /// Fallback for statuses not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl NumberOrderStatus {
+    /// Whether the status will no longer change
+    pub fn is_final(&self) -> bool {
+        matches!(self, Self::Success | Self::Failure)
+    }
+}
+
+/// A regulatory requirement of an ordered phone number
+///
+/// Returned on ordered numbers, and sent with `requirement_id` and
+/// `field_value` to fulfil a requirement.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct RegulatoryRequirement {
+    /// Unique ID of the requirement.
+    pub requirement_id: String,
+    /// The kind of value expected, e.g. `textual`, `datetime`, `address` or `document`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<String>,
+    /// The value fulfilling the requirement: text, a date, or the ID of an address or document.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_value: Option<String>,
+}
+
+/// A sub number order, grouping the numbers of an order by country and type
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SubNumberOrder {
+    /// Uniquely identifies the sub number order.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// Identifies the number order the sub number order belongs to.
+    pub order_request_id: String,
+    /// ISO 3166-1 alpha-2 country code of the numbers.
+    pub country_code: String,
+    /// The type of the numbers.
+    #[serde(default)]
+    pub phone_number_type: PhoneNumberType,
+    /// The number of phone numbers in the sub number order.
+    #[serde(default)]
+    pub phone_numbers_count: i32,
+    /// The status of the sub number order.
+    #[serde(default)]
+    pub status: NumberOrderStatus,
+    /// True if all requirements are met for every phone number in the sub number order.
+    #[serde(default)]
+    pub requirements_met: bool,
+    /// The regulatory requirements that apply to the numbers.
+    #[serde(default)]
+    pub regulatory_requirements: Vec<SubNumberOrderRequirement>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A regulatory requirement that applies to a sub number order
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct SubNumberOrderRequirement {
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// Unique ID of the requirement.
+    pub requirement_id: String,
+    /// The kind of value expected, e.g. `textual`, `datetime`, `address` or `document`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field_type: Option<String>,
+}
+
+/// Query parameters for listing number orders
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListNumberOrdersParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by order status.
+    pub status: Option<NumberOrderStatus>,
+    /// Filter orders created after this time.
+    pub created_at_gt: Option<DateTime<Utc>>,
+    /// Filter orders created before this time.
+    pub created_at_lt: Option<DateTime<Utc>>,
+    /// Filter orders containing this phone number.
+    pub phone_number: Option<String>,
+    /// Filter by customer reference.
+    pub customer_reference: Option<String>,
+    /// Filter by whether all requirements are met.
+    pub requirements_met: Option<bool>,
+}
+
+impl ToQuery for ListNumberOrdersParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter("status", self.status)
+            .filter_op("created_at", "gt", self.created_at_gt)
+            .filter_op("created_at", "lt", self.created_at_lt)
+            .filter("phone_numbers.phone_number", self.phone_number.as_ref())
+            .filter("customer_reference", self.customer_reference.as_ref())
+            .filter("requirements_met", self.requirements_met)
+    }
+}
+
+impl Paginated for ListNumberOrdersParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// A phone number to order
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct PhoneNumberOrderRequest {
+    /// The +E.164-formatted phone number to order.
+    pub phone_number: String,
+    /// Identifies a requirement group fulfilling the number's regulatory requirements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub requirement_group_id: Option<String>,
+    /// Values fulfilling the number's regulatory requirements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regulatory_requirements: Option<Vec<RegulatoryRequirement>>,
+}
+
+/// A request to order phone numbers
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CreateNumberOrderRequest {
+    /// The phone numbers to order.
+    pub phone_numbers: Vec<PhoneNumberOrderRequest>,
+    /// Identifies the connection to associate with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// Identifies the messaging profile to associate with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// Identifies the billing group to associate with the ordered numbers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_group_id: Option<String>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+}
+
+/// A request to update a number order
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UpdateNumberOrderRequest {
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// Values fulfilling outstanding regulatory requirements.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regulatory_requirements: Option<Vec<RegulatoryRequirement>>,
+}
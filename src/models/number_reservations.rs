@@ -0,0 +1,152 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+/// A reservation holding phone numbers before they are ordered
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct NumberReservation {
+    /// Uniquely identifies the number reservation.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The reserved phone numbers.
+    #[serde(default)]
+    pub phone_numbers: Vec<ReservedPhoneNumber>,
+    /// The status of the reservation.
+    #[serde(default)]
+    pub status: ReservationStatus,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A phone number held by a reservation
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct ReservedPhoneNumber {
+    /// Uniquely identifies the reserved phone number.
+    pub id: String,
+    /// Identifies the type of the resource.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_type: Option<String>,
+    /// The +E.164-formatted phone number.
+    pub phone_number: String,
+    /// The reservation status of the phone number.
+    #[serde(default)]
+    pub status: ReservedPhoneNumberStatus,
+    /// ISO 8601 formatted date indicating when the reservation of the number expires.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expired_at: Option<DateTime<Utc>>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+/// Status of a number reservation, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationStatus {
+    /// The reservation is being processed
+    Pending,
+    /// The numbers are reserved
+    Success,
+    /// The reservation failed
+    Failure,
+    /// Fallback for statuses not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Status of a reserved phone number, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservedPhoneNumberStatus {
+    /// The reservation of the number is being processed
+    Pending,
+    /// The number is reserved
+    Success,
+    /// The number could not be reserved
+    Failure,
+    /// The reservation of the number has expired
+    Expired,
+    /// Fallback for statuses not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Query parameters for listing number reservations
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListNumberReservationsParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by reservation status.
+    pub status: Option<ReservationStatus>,
+    /// Filter reservations created after this time.
+    pub created_at_gt: Option<DateTime<Utc>>,
+    /// Filter reservations created before this time.
+    pub created_at_lt: Option<DateTime<Utc>>,
+    /// Filter reservations holding this phone number.
+    pub phone_number: Option<String>,
+    /// Filter by customer reference.
+    pub customer_reference: Option<String>,
+}
+
+impl ToQuery for ListNumberReservationsParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter("status", self.status)
+            .filter_op("created_at", "gt", self.created_at_gt)
+            .filter_op("created_at", "lt", self.created_at_lt)
+            .filter("phone_numbers.phone_number", self.phone_number.as_ref())
+            .filter("customer_reference", self.customer_reference.as_ref())
+    }
+}
+
+impl Paginated for ListNumberReservationsParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// A phone number to reserve
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct PhoneNumberReservationRequest {
+    /// The +E.164-formatted phone number to reserve.
+    pub phone_number: String,
+}
+
+/// A request to reserve phone numbers
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CreateNumberReservationRequest {
+    /// The phone numbers to reserve.
+    pub phone_numbers: Vec<PhoneNumberReservationRequest>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+}
@@ -0,0 +1,443 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    models::common::redacted,
+    pagination::Paginated,
+    query::{Query, ToQuery},
+};
+
+/// A phone number owned by the account
+///
+/// The `Debug` output redacts the external PIN.
+#[derive(Clone, Serialize, Deserialize, Default)]
+pub struct PhoneNumber {
+    /// Uniquely identifies the phone number.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The +E.164-formatted phone number associated with this record.
+    pub phone_number: String,
+    /// The phone number's current status.
+    #[serde(default)]
+    pub status: PhoneNumberStatus,
+    /// A list of user-assigned tags to help manage the phone number.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// If someone attempts to port your phone number away from Telnyx, they will be asked for this PIN.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_pin: Option<String>,
+    /// Identifies the connection associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// The user-assigned name of the connection associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_name: Option<String>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// Identifies the messaging profile associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// The name of the messaging profile associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_name: Option<String>,
+    /// Identifies the billing group associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_group_id: Option<String>,
+    /// Indicates whether emergency services are enabled for this number.
+    #[serde(default)]
+    pub emergency_enabled: bool,
+    /// Identifies the emergency address associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emergency_address_id: Option<String>,
+    /// Indicates if call forwarding is enabled for this number.
+    #[serde(default)]
+    pub call_forwarding_enabled: bool,
+    /// Indicates whether a CNAM listing is enabled for this number.
+    #[serde(default)]
+    pub cnam_listing_enabled: bool,
+    /// Indicates whether caller ID is enabled for this number.
+    #[serde(default)]
+    pub caller_id_name_enabled: bool,
+    /// Indicates if call recording is enabled for this number.
+    #[serde(default)]
+    pub call_recording_enabled: bool,
+    /// Indicates whether T38 Fax Gateway for inbound calls to this number is enabled.
+    #[serde(default)]
+    pub t38_fax_gateway_enabled: bool,
+    /// The phone number's type, e.g. `local` or `toll_free`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number_type: Option<String>,
+    /// ISO 8601 formatted date indicating when the phone number was purchased.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purchased_at: Option<DateTime<Utc>>,
+    /// ISO 8601 formatted date indicating when the resource was created.
+    pub created_at: DateTime<Utc>,
+    /// ISO 8601 formatted date indicating when the resource was updated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl std::fmt::Debug for PhoneNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PhoneNumber")
+            .field("id", &self.id)
+            .field("record_type", &self.record_type)
+            .field("phone_number", &self.phone_number)
+            .field("status", &self.status)
+            .field("tags", &self.tags)
+            .field("external_pin", &redacted(self.external_pin.as_deref()))
+            .field("connection_id", &self.connection_id)
+            .field("connection_name", &self.connection_name)
+            .field("customer_reference", &self.customer_reference)
+            .field("messaging_profile_id", &self.messaging_profile_id)
+            .field("messaging_profile_name", &self.messaging_profile_name)
+            .field("billing_group_id", &self.billing_group_id)
+            .field("emergency_enabled", &self.emergency_enabled)
+            .field("emergency_address_id", &self.emergency_address_id)
+            .field("call_forwarding_enabled", &self.call_forwarding_enabled)
+            .field("cnam_listing_enabled", &self.cnam_listing_enabled)
+            .field("caller_id_name_enabled", &self.caller_id_name_enabled)
+            .field("call_recording_enabled", &self.call_recording_enabled)
+            .field("t38_fax_gateway_enabled", &self.t38_fax_gateway_enabled)
+            .field("phone_number_type", &self.phone_number_type)
+            .field("purchased_at", &self.purchased_at)
+            .field("created_at", &self.created_at)
+            .field("updated_at", &self.updated_at)
+            .finish()
+    }
+}
+
+/// Status of a phone number, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum PhoneNumberStatus {
+    /// The purchase is being processed
+    PurchasePending,
+    /// The purchase failed
+    PurchaseFailed,
+    /// The number is being ported in
+    PortPending,
+    /// The port-in failed
+    PortFailed,
+    /// The number is active
+    Active,
+    /// The number has been deleted
+    Deleted,
+    /// The number can only be used for emergency calls
+    EmergencyOnly,
+    /// The number has been ported out
+    PortedOut,
+    /// The number is being ported out
+    PortOutPending,
+    /// Regulatory requirements must be provided before the number is active
+    RequirementInfoPending,
+    /// Regulatory requirements are being reviewed
+    RequirementInfoUnderReview,
+    /// Regulatory requirements were rejected
+    RequirementInfoException,
+    /// The number is being provisioned
+    ProvisionPending,
+    /// Fallback for statuses not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Query parameters for listing phone numbers
+#[derive(Debug, Clone, Default, Builder)]
+#[builder(on(String, into))]
+pub struct ListPhoneNumbersParams {
+    /// The page number to load.
+    pub page_number: Option<i32>,
+    /// The size of the page.
+    pub page_size: Option<i32>,
+    /// Filter by phone number tag.
+    pub tag: Option<String>,
+    /// Filter by a full or partial phone number.
+    pub phone_number: Option<String>,
+    /// Filter by status.
+    pub status: Option<PhoneNumberStatus>,
+    /// Filter by connection ID.
+    pub connection_id: Option<String>,
+    /// Filter by a partial connection name.
+    pub connection_name_contains: Option<String>,
+    /// Filter by billing group ID.
+    pub billing_group_id: Option<String>,
+    /// Filter by whether emergency services are enabled.
+    pub emergency_enabled: Option<bool>,
+    /// Filter by customer reference.
+    pub customer_reference: Option<String>,
+    /// The sort order of the results.
+    pub sort: Option<PhoneNumberSort>,
+}
+
+impl ToQuery for ListPhoneNumbersParams {
+    fn to_query(&self) -> Query {
+        Query::new()
+            .page(self.page_number, self.page_size)
+            .filter("tag", self.tag.as_ref())
+            .filter("phone_number", self.phone_number.as_ref())
+            .filter("status", self.status)
+            .filter("connection_id", self.connection_id.as_ref())
+            .filter_op(
+                "voice.connection_name",
+                "contains",
+                self.connection_name_contains.as_ref(),
+            )
+            .filter("billing_group_id", self.billing_group_id.as_ref())
+            .filter("emergency_enabled", self.emergency_enabled)
+            .filter("customer_reference", self.customer_reference.as_ref())
+            .sort(self.sort)
+    }
+}
+
+impl Paginated for ListPhoneNumbersParams {
+    fn page_number(&self) -> Option<i32> {
+        self.page_number
+    }
+
+    fn with_page(&self, page: i32) -> Self {
+        Self {
+            page_number: Some(page),
+            ..self.clone()
+        }
+    }
+}
+
+/// Sort order for listing phone numbers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum PhoneNumberSort {
+    /// Oldest purchase first
+    #[serde(rename = "purchased_at")]
+    PurchasedAt,
+    /// Newest purchase first
+    #[serde(rename = "-purchased_at")]
+    PurchasedAtDesc,
+    /// Phone number, ascending
+    #[serde(rename = "phone_number")]
+    PhoneNumber,
+    /// Phone number, descending
+    #[serde(rename = "-phone_number")]
+    PhoneNumberDesc,
+    /// Connection name, ascending
+    #[serde(rename = "connection_name")]
+    ConnectionName,
+    /// Connection name, descending
+    #[serde(rename = "-connection_name")]
+    ConnectionNameDesc,
+}
+
+/// A request to update a phone number
+///
+/// The `Debug` output redacts the external PIN.
+#[derive(Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UpdatePhoneNumberRequest {
+    /// A list of user-assigned tags, replacing the current tags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// Identifies the connection associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// Identifies the billing group associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub billing_group_id: Option<String>,
+    /// If someone attempts to port your phone number away from Telnyx, they will be asked for this PIN.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_pin: Option<String>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+}
+
+impl std::fmt::Debug for UpdatePhoneNumberRequest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UpdatePhoneNumberRequest")
+            .field("tags", &self.tags)
+            .field("connection_id", &self.connection_id)
+            .field("billing_group_id", &self.billing_group_id)
+            .field("external_pin", &redacted(self.external_pin.as_deref()))
+            .field("customer_reference", &self.customer_reference)
+            .finish()
+    }
+}
+
+/// Voice settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct PhoneNumberVoiceSettings {
+    /// Identifies the phone number.
+    pub id: String,
+    /// Identifies the type of the resource.
+    pub record_type: String,
+    /// The +E.164-formatted phone number associated with this record.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+    /// Identifies the connection associated with the phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_id: Option<String>,
+    /// A customer reference string for customer look ups.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub customer_reference: Option<String>,
+    /// Controls whether a tech prefix is enabled for this phone number.
+    #[serde(default)]
+    pub tech_prefix_enabled: bool,
+    /// This field allows you to rewrite the destination number of an inbound call before the call is routed to you.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translated_number: Option<String>,
+    /// Controls whether caller ID name is enabled for this phone number.
+    #[serde(default)]
+    pub caller_id_name_enabled: bool,
+    /// Controls whether a number is billed per minute or uses your concurrent channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_payment_method: Option<UsagePaymentMethod>,
+    /// Call forwarding settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_forwarding: Option<CallForwarding>,
+    /// CNAM listing settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnam_listing: Option<CnamListing>,
+    /// Call recording settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_recording: Option<CallRecording>,
+    /// Media features settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_features: Option<MediaFeatures>,
+    /// Emergency services settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emergency: Option<EmergencySettings>,
+}
+
+/// How a phone number's voice usage is billed, with an unknown fallback
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum UsagePaymentMethod {
+    /// Billed per minute
+    PayPerMinute,
+    /// Uses the account's concurrent channels
+    Channel,
+    /// Fallback for methods not known to this version of the crate
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+/// Call forwarding settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CallForwarding {
+    /// Indicates if call forwarding will be enabled for this number if forwards_to and forwarding_type are filled in.
+    #[serde(default)]
+    #[builder(default)]
+    pub call_forwarding_enabled: bool,
+    /// The phone number to which inbound calls to this number are forwarded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forwards_to: Option<String>,
+    /// Whether calls are always forwarded (`always`) or only when unanswered (`on-failure`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub forwarding_type: Option<String>,
+}
+
+/// CNAM listing settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CnamListing {
+    /// Enables CNAM listings for this number.
+    #[serde(default)]
+    #[builder(default)]
+    pub cnam_listing_enabled: bool,
+    /// The CNAM listing details for this number, at most 15 characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnam_listing_details: Option<String>,
+}
+
+/// Call recording settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct CallRecording {
+    /// When enabled, any inbound call to this number will be recorded.
+    #[serde(default)]
+    #[builder(default)]
+    pub inbound_call_recording_enabled: bool,
+    /// The audio file format for calls being recorded, `wav` or `mp3`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound_call_recording_format: Option<String>,
+    /// Whether calls are recorded in `single` or `dual` channel.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inbound_call_recording_channels: Option<String>,
+}
+
+/// Media features settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+pub struct MediaFeatures {
+    /// When enabled, the destination RTP address and port follow the source of incoming RTP packets.
+    #[serde(default)]
+    #[builder(default)]
+    pub rtp_auto_adjust_enabled: bool,
+    /// When enabled, Telnyx will accept RTP packets from any customer-side IP address and port.
+    #[serde(default)]
+    #[builder(default)]
+    pub accept_any_rtp_packets_enabled: bool,
+    /// Controls whether T.38 fax gateway is enabled for inbound calls to this number.
+    #[serde(default)]
+    #[builder(default)]
+    pub t38_fax_gateway_enabled: bool,
+}
+
+/// Emergency services settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct EmergencySettings {
+    /// Allows you to enable or disable emergency services on the phone number.
+    #[serde(default)]
+    pub emergency_enabled: bool,
+    /// Identifies the address to be used with emergency services.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emergency_address_id: Option<String>,
+    /// Represents the state of the number regarding emergency activation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emergency_status: Option<String>,
+}
+
+/// A request to update the voice settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UpdatePhoneNumberVoiceSettingsRequest {
+    /// Controls whether a tech prefix is enabled for this phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tech_prefix_enabled: Option<bool>,
+    /// This field allows you to rewrite the destination number of an inbound call before the call is routed to you.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translated_number: Option<String>,
+    /// Controls whether caller ID name is enabled for this phone number.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub caller_id_name_enabled: Option<bool>,
+    /// Controls whether a number is billed per minute or uses your concurrent channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_payment_method: Option<UsagePaymentMethod>,
+    /// Call forwarding settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_forwarding: Option<CallForwarding>,
+    /// CNAM listing settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cnam_listing: Option<CnamListing>,
+    /// Call recording settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub call_recording: Option<CallRecording>,
+    /// Media features settings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub media_features: Option<MediaFeatures>,
+}
+
+/// A request to update the messaging settings of a phone number
+#[derive(Debug, Clone, Serialize, Deserialize, Default, Builder)]
+#[builder(on(String, into))]
+pub struct UpdatePhoneNumberMessagingRequest {
+    /// Identifies the messaging profile to assign, or an empty string to unassign the current one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_profile_id: Option<String>,
+    /// The messaging product that the number should be registered to use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub messaging_product: Option<String>,
+}
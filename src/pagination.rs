@@ -0,0 +1,159 @@
+use futures::{Stream, TryStreamExt, future::BoxFuture};
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use crate::{error::TelnyxError, models::ApiListResponse, query::ToQuery};
+
+type FetchPage<'a, T> =
+    Box<dyn Fn(i32) -> BoxFuture<'a, Result<ApiListResponse<T>, TelnyxError>> + Send + Sync + 'a>;
+
+/// Query parameters of a list endpoint that [`Paginator`] can page through
+pub(crate) trait Paginated: ToQuery + Clone + Send + Sync + 'static {
+    /// The page to start from, if set
+    fn page_number(&self) -> Option<i32>;
+
+    /// These parameters with the page number replaced by `page`
+    fn with_page(&self, page: i32) -> Self;
+}
+
+/// Auto-paginating stream over a list endpoint
+///
+/// Pages are fetched on demand as items are consumed, until the last page
+/// reported by [`PaginationMeta`](crate::models::PaginationMeta) is reached.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// # use telnyx_rs::TelnyxClient;
+/// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+/// // Stream every address
+/// let mut addresses = client.addresses().list_all(None);
+/// while let Some(address) = addresses.next().await {
+///     println!("{}", address?.id);
+/// }
+///
+/// // Collect at most 100 addresses from the first 5 pages
+/// let addresses = client
+///     .addresses()
+///     .list_all(None)
+///     .max_pages(5)
+///     .max_items(100)
+///     .collect_all()
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<'a, T> {
+    fetch: FetchPage<'a, T>,
+    next_page: Option<i32>,
+    in_flight: Option<BoxFuture<'a, Result<ApiListResponse<T>, TelnyxError>>>,
+    buffer: VecDeque<T>,
+    pages_fetched: usize,
+    items_yielded: usize,
+    max_pages: Option<usize>,
+    max_items: Option<usize>,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    /// Create a paginator starting at `first_page`, fetching pages with `fetch`
+    pub(crate) fn new<F>(first_page: i32, fetch: F) -> Self
+    where
+        F: Fn(i32) -> BoxFuture<'a, Result<ApiListResponse<T>, TelnyxError>> + Send + Sync + 'a,
+    {
+        Self {
+            fetch: Box::new(fetch),
+            next_page: Some(first_page),
+            in_flight: None,
+            buffer: VecDeque::new(),
+            pages_fetched: 0,
+            items_yielded: 0,
+            max_pages: None,
+            max_items: None,
+        }
+    }
+
+    /// Stop after fetching `pages` pages, as a safety cap on large result sets
+    ///
+    /// With a cap of 0, no page is fetched and the stream is empty.
+    pub fn max_pages(mut self, pages: usize) -> Self {
+        self.max_pages = Some(pages);
+        self
+    }
+
+    /// Stop after yielding `items` items
+    pub fn max_items(mut self, items: usize) -> Self {
+        self.max_items = Some(items);
+        self
+    }
+
+    /// Fetch every remaining page and collect the items
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error encountered while fetching a page.
+    pub async fn collect_all(self) -> Result<Vec<T>, TelnyxError> {
+        self.try_collect().await
+    }
+
+    fn following_page(&self, page: &ApiListResponse<T>) -> Option<i32> {
+        let meta = page.meta.as_ref()?;
+        let more_pages = meta.page_number < meta.total_pages && !page.data.is_empty();
+
+        more_pages.then_some(meta.page_number + 1)
+    }
+}
+
+impl<T> Unpin for Paginator<'_, T> {}
+
+impl<T> Stream for Paginator<'_, T> {
+    type Item = Result<T, TelnyxError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if this.max_items.is_some_and(|max| this.items_yielded >= max) {
+                return Poll::Ready(None);
+            }
+
+            if let Some(item) = this.buffer.pop_front() {
+                this.items_yielded += 1;
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if let Some(in_flight) = this.in_flight.as_mut() {
+                let result = match in_flight.as_mut().poll(cx) {
+                    Poll::Ready(result) => result,
+                    Poll::Pending => return Poll::Pending,
+                };
+                this.in_flight = None;
+
+                match result {
+                    Ok(page) => {
+                        this.pages_fetched += 1;
+                        this.next_page = this.following_page(&page);
+                        this.buffer.extend(page.data);
+                        continue;
+                    }
+                    Err(error) => {
+                        this.next_page = None;
+                        return Poll::Ready(Some(Err(error)));
+                    }
+                }
+            }
+
+            if this.max_pages.is_some_and(|max| this.pages_fetched >= max) {
+                return Poll::Ready(None);
+            }
+
+            match this.next_page.take() {
+                Some(page) => this.in_flight = Some((this.fetch)(page)),
+                None => return Poll::Ready(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Query strings in the Telnyx list parameter syntax
+//!
+//! List endpoints take nested bracket parameters such as
+//! `filter[phone_number][contains]=555&page[size]=20&sort=-created_at`.
+//! Parameter structs implement [`ToQuery`] to build a [`Query`], which the
+//! client encodes into the request URL.
+//!
+//! # Example
+//!
+//! ```
+//! use telnyx_rs::query::Query;
+//!
+//! let query = Query::new()
+//!     .filter_op("phone_number", "contains", Some("555"))
+//!     .page(Some(2), Some(20))
+//!     .sort(Some("-created_at"));
+//!
+//! assert_eq!(
+//!     query.to_query_string(),
+//!     "filter%5Bphone_number%5D%5Bcontains%5D=555&page%5Bnumber%5D=2&page%5Bsize%5D=20&sort=-created_at"
+//! );
+//! ```
+
+use serde::{Serialize, Serializer, ser::SerializeSeq};
+
+/// Parameters that can be encoded as a [`Query`]
+pub trait ToQuery {
+    /// Build the query for these parameters
+    fn to_query(&self) -> Query;
+}
+
+/// An ordered list of query parameters
+///
+/// Every method skips `None` values, so optional struct fields can be passed
+/// directly. Values are encoded from their serde representation: strings
+/// and unit enum variants as-is, numbers and booleans in their JSON form.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Query {
+    pairs: Vec<(String, String)>,
+}
+
+impl Query {
+    /// An empty query
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `name=value`
+    pub fn param<V: Serialize>(mut self, name: &str, value: Option<V>) -> Self {
+        if let Some(value) = value.as_ref().and_then(encode) {
+            self.pairs.push((name.to_string(), value));
+        }
+        self
+    }
+
+    /// Add `filter[field]=value`
+    pub fn filter<V: Serialize>(self, field: &str, value: Option<V>) -> Self {
+        self.param(&format!("filter[{field}]"), value)
+    }
+
+    /// Add `filter[field][operator]=value`, e.g. `filter[email][contains]`
+    pub fn filter_op<V: Serialize>(self, field: &str, operator: &str, value: Option<V>) -> Self {
+        self.param(&format!("filter[{field}][{operator}]"), value)
+    }
+
+    /// Add `page[number]` and `page[size]`
+    pub fn page(self, number: Option<i32>, size: Option<i32>) -> Self {
+        self.param("page[number]", number).param("page[size]", size)
+    }
+
+    /// Add `sort=value`
+    pub fn sort<V: Serialize>(self, sort: Option<V>) -> Self {
+        self.param("sort", sort)
+    }
+
+    /// Append every parameter of `other`
+    pub fn extend(mut self, other: Query) -> Self {
+        self.pairs.extend(other.pairs);
+        self
+    }
+
+    /// The parameters in insertion order
+    pub fn pairs(&self) -> &[(String, String)] {
+        &self.pairs
+    }
+
+    /// Whether the query has no parameters
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+
+    /// The URL-encoded query string, without the leading `?`
+    pub fn to_query_string(&self) -> String {
+        serde_urlencoded::to_string(&self.pairs).unwrap_or_default()
+    }
+}
+
+impl ToQuery for Query {
+    fn to_query(&self) -> Query {
+        self.clone()
+    }
+}
+
+impl Serialize for Query {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.pairs.len()))?;
+        for pair in &self.pairs {
+            seq.serialize_element(pair)?;
+        }
+        seq.end()
+    }
+}
+
+/// Encode a scalar value, or `None` for null and values that are not scalars
+fn encode<V: Serialize>(value: &V) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::String(value) => Some(value),
+        serde_json::Value::Bool(value) => Some(value.to_string()),
+        serde_json::Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
@@ -0,0 +1,245 @@
+use reqwest::{StatusCode, header::HeaderMap};
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use crate::{error::TelnyxError, retry::parse_retry_after};
+
+/// A number of requests allowed per period
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quota {
+    /// Requests allowed per period, also the maximum burst
+    pub requests: u32,
+    /// Length of the period
+    pub period: Duration,
+}
+
+impl Quota {
+    /// Allow `requests` requests every `period`
+    pub fn new(requests: u32, period: Duration) -> Self {
+        Self { requests, period }
+    }
+
+    /// Allow `requests` requests per second
+    pub fn per_second(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(1))
+    }
+
+    /// Allow `requests` requests per minute
+    pub fn per_minute(requests: u32) -> Self {
+        Self::new(requests, Duration::from_secs(60))
+    }
+}
+
+/// Client-side rate limits applied before requests are sent
+///
+/// Requests wait for a token from the global bucket and from the bucket of
+/// their endpoint group, which is the first segment of the request path
+/// (`addresses` for `/addresses/123`). The limiter also pauses an endpoint
+/// group when a response reports an exhausted limit through `Retry-After`
+/// on a 429, or through `x-ratelimit-remaining: 0` and `x-ratelimit-reset`.
+/// Such pauses are capped at the retry policy's
+/// [`max_delay`](crate::RetryPolicy::max_delay).
+///
+/// # Example
+///
+/// ```no_run
+/// use telnyx_rs::{Quota, RateLimitPolicy, TelnyxClient};
+///
+/// # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+/// let client = TelnyxClient::builder()
+///     .api_key("your-api-key")
+///     .rate_limit(
+///         RateLimitPolicy::new()
+///             .global(Quota::per_second(20))
+///             .group("addresses", Quota::per_second(5)),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitPolicy {
+    global: Option<Quota>,
+    groups: HashMap<String, Quota>,
+}
+
+impl RateLimitPolicy {
+    /// A policy without quotas that only adapts to rate limit headers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limit all requests sent by the client
+    pub fn global(mut self, quota: Quota) -> Self {
+        self.global = Some(quota);
+        self
+    }
+
+    /// Limit requests to an endpoint group, such as `addresses`
+    pub fn group(mut self, group: impl Into<String>, quota: Quota) -> Self {
+        self.groups.insert(group.into(), quota);
+        self
+    }
+
+    fn quotas(&self) -> impl Iterator<Item = &Quota> {
+        self.global.iter().chain(self.groups.values())
+    }
+}
+
+/// Runtime state of a [`RateLimitPolicy`]
+pub(crate) struct RateLimiter {
+    global: Option<TokenBucket>,
+    groups: HashMap<String, TokenBucket>,
+    paused_until: Mutex<HashMap<String, Instant>>,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(policy: RateLimitPolicy) -> Result<Self, TelnyxError> {
+        if policy
+            .quotas()
+            .any(|quota| quota.requests == 0 || quota.period.is_zero())
+        {
+            return Err(TelnyxError::Config(
+                "Rate limit quotas require a non-zero number of requests and period".into(),
+            ));
+        }
+
+        Ok(Self {
+            global: policy.global.map(TokenBucket::new),
+            groups: policy
+                .groups
+                .into_iter()
+                .map(|(group, quota)| (group, TokenBucket::new(quota)))
+                .collect(),
+            paused_until: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Wait until a request to `path` may be sent
+    pub(crate) async fn acquire(&self, path: &str) {
+        let group = endpoint_group(path);
+
+        let paused_until = {
+            let mut paused = self.lock_paused();
+            remove_expired(&mut paused);
+            paused.get(group).copied()
+        };
+        if let Some(until) = paused_until {
+            tokio::time::sleep_until(until.into()).await;
+        }
+
+        if let Some(bucket) = &self.global {
+            bucket.acquire().await;
+        }
+        if let Some(bucket) = self.groups.get(group) {
+            bucket.acquire().await;
+        }
+    }
+
+    /// Pause the endpoint group of `path` for at most `max_pause` if the
+    /// response reports an exhausted rate limit
+    pub(crate) fn observe(
+        &self,
+        path: &str,
+        status: StatusCode,
+        headers: &HeaderMap,
+        max_pause: Duration,
+    ) {
+        let pause = if status == StatusCode::TOO_MANY_REQUESTS {
+            parse_retry_after(headers)
+        } else {
+            None
+        };
+        let pause = pause.or_else(|| {
+            let header = |name| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+            match header("x-ratelimit-remaining") {
+                Some(0) => header("x-ratelimit-reset").map(Duration::from_secs),
+                _ => None,
+            }
+        });
+
+        let until = pause.and_then(|pause| Instant::now().checked_add(pause.min(max_pause)));
+        if let Some(until) = until {
+            let mut paused = self.lock_paused();
+            remove_expired(&mut paused);
+            let entry = paused
+                .entry(endpoint_group(path).to_string())
+                .or_insert(until);
+            *entry = (*entry).max(until);
+        }
+    }
+
+    fn lock_paused(&self) -> std::sync::MutexGuard<'_, HashMap<String, Instant>> {
+        self.paused_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn remove_expired(paused: &mut HashMap<String, Instant>) {
+    let now = Instant::now();
+    paused.retain(|_, until| *until > now);
+}
+
+/// A token bucket refilled continuously at `requests / period`
+struct TokenBucket {
+    quota: Quota,
+    state: Mutex<BucketState>,
+}
+
+struct BucketState {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+impl TokenBucket {
+    fn new(quota: Quota) -> Self {
+        Self {
+            quota,
+            state: Mutex::new(BucketState {
+                tokens: f64::from(quota.requests),
+                refilled_at: Instant::now(),
+            }),
+        }
+    }
+
+    async fn acquire(&self) {
+        while let Some(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Take a token, or return how long to wait for the next one
+    fn try_acquire(&self) -> Option<Duration> {
+        let capacity = f64::from(self.quota.requests);
+        let rate = capacity / self.quota.period.as_secs_f64();
+
+        let mut state = self
+            .state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        let elapsed = now.duration_since(state.refilled_at).as_secs_f64();
+        state.tokens = (state.tokens + elapsed * rate).min(capacity);
+        state.refilled_at = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            None
+        } else {
+            // Very long periods give waits beyond `Duration::MAX`; sleeping
+            // that long is the same as never refilling.
+            Some(Duration::try_from_secs_f64((1.0 - state.tokens) / rate).unwrap_or(Duration::MAX))
+        }
+    }
+}
+
+fn endpoint_group(path: &str) -> &str {
+    path.trim_start_matches('/')
+        .split(['/', '?'])
+        .next()
+        .unwrap_or_default()
+}
@@ -0,0 +1,163 @@
+use bytes::Bytes;
+use reqwest::Method;
+use serde::{Serialize, de::DeserializeOwned};
+
+use crate::{
+    client::TelnyxClient, error::TelnyxError, request_options::RequestOptions,
+    response::ResponseMeta,
+};
+
+/// A request to an arbitrary API path, created with [`TelnyxClient::request()`]
+///
+/// Requests share authentication, the base URL, retries, rate limiting,
+/// middleware and error parsing with the typed endpoints. Serialization
+/// errors from [`query`](Self::query) and [`json`](Self::json) are reported
+/// when the request is sent.
+///
+/// # Example
+///
+/// ```no_run
+/// use serde::Deserialize;
+/// use telnyx_rs::{Method, TelnyxClient, models::ApiResponse};
+///
+/// #[derive(Deserialize)]
+/// struct Balance {
+///     balance: String,
+///     currency: String,
+/// }
+///
+/// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+/// let balance: ApiResponse<Balance> = client.request(Method::GET, "/balance").send().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct RequestBuilder {
+    client: TelnyxClient,
+    method: Method,
+    path: String,
+    query: Vec<(String, String)>,
+    body: Option<Vec<u8>>,
+    options: RequestOptions,
+    error: Option<TelnyxError>,
+}
+
+impl RequestBuilder {
+    pub(crate) fn new(client: TelnyxClient, method: Method, path: &str) -> Self {
+        let path = if path.starts_with('/') {
+            path.to_string()
+        } else {
+            format!("/{path}")
+        };
+
+        Self {
+            client,
+            method,
+            path,
+            query: Vec::new(),
+            body: None,
+            options: RequestOptions::default(),
+            error: None,
+        }
+    }
+
+    /// Append query parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - Any value that serializes to key-value pairs, such as a
+    ///   struct, a map or a slice of tuples
+    pub fn query<Q: Serialize + ?Sized>(mut self, query: &Q) -> Self {
+        let pairs = serde_urlencoded::to_string(query)
+            .map_err(|e| e.to_string())
+            .and_then(|encoded| {
+                serde_urlencoded::from_str::<Vec<(String, String)>>(&encoded)
+                    .map_err(|e| e.to_string())
+            });
+        match pairs {
+            Ok(pairs) => self.query.extend(pairs),
+            Err(e) => {
+                let error = TelnyxError::InvalidRequest(format!("Invalid query: {e}"));
+                self.error.get_or_insert(error);
+            }
+        }
+        self
+    }
+
+    /// Send `body` as JSON
+    pub fn json<B: Serialize + ?Sized>(mut self, body: &B) -> Self {
+        match serde_json::to_vec(body) {
+            Ok(body) => self.body = Some(body),
+            Err(e) => {
+                self.error.get_or_insert(TelnyxError::Parse(e));
+            }
+        }
+        self
+    }
+
+    /// Use `options` for this request
+    ///
+    /// Telnyx only honors idempotency keys on POST requests, so sending a
+    /// request with any other method and a key fails with
+    /// [`TelnyxError::InvalidRequest`].
+    pub fn options(mut self, options: RequestOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Send the request and deserialize the response body
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TelnyxError::Api`] for non-2xx responses and
+    /// [`TelnyxError::Parse`] if the body does not match `T`.
+    pub async fn send<T: DeserializeOwned>(self) -> Result<T, TelnyxError> {
+        let (meta, body) = self.send_inner().await?;
+        if meta.status.is_success() {
+            Ok(serde_json::from_slice(&body)?)
+        } else {
+            Err(TelnyxClient::api_error(meta, &body))
+        }
+    }
+
+    /// Send the request and discard the response body
+    ///
+    /// Useful for endpoints that respond without content, such as most
+    /// deletions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TelnyxError::Api`] for non-2xx responses.
+    pub async fn send_empty(self) -> Result<ResponseMeta, TelnyxError> {
+        let (meta, body) = self.send_inner().await?;
+        if meta.status.is_success() {
+            Ok(meta)
+        } else {
+            Err(TelnyxClient::api_error(meta, &body))
+        }
+    }
+
+    async fn send_inner(self) -> Result<(ResponseMeta, Bytes), TelnyxError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        if self.method != Method::POST && self.options.has_idempotency_key() {
+            return Err(TelnyxError::InvalidRequest(format!(
+                "Idempotency keys are only supported on POST requests, not {}",
+                self.method
+            )));
+        }
+
+        let idempotency_key = self.client.idempotency_key(&self.method, &self.options);
+        self.client
+            .send(
+                self.method,
+                &self.path,
+                &self.query,
+                self.body,
+                idempotency_key.as_deref(),
+                &self.options,
+            )
+            .await
+    }
+}
@@ -0,0 +1,109 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use crate::retry::RetryPolicy;
+
+/// Settings that override the client configuration for individual calls
+///
+/// Attach options to an endpoint handle with `with_options`; every call made
+/// through that handle uses them, except the idempotency key, which is only
+/// sent once.
+///
+/// There is no managed account override: the Telnyx API only acts on a
+/// managed account when called with that account's own API key. To make
+/// calls on behalf of a sub-account, scope a client with
+/// [`TelnyxClient::for_managed_account()`](crate::TelnyxClient::for_managed_account)
+/// and use its endpoint handles, which are cheap to create per call.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use telnyx_rs::{RequestOptions, TelnyxClient, models::CreateAddressRequest};
+///
+/// # async fn example(client: &TelnyxClient, request: CreateAddressRequest) -> Result<(), telnyx_rs::TelnyxError> {
+/// let options = RequestOptions::new()
+///     .timeout(Duration::from_secs(120))
+///     .header("x-correlation-id", "batch-42")
+///     .idempotency_key("create-address-order-1234");
+///
+/// let address = client.addresses().with_options(options).create(request).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestOptions {
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) idempotency_key: Arc<Mutex<Option<String>>>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
+}
+
+impl RequestOptions {
+    /// Options that keep every client setting
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Override the total timeout of each attempt
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Add a header to every request
+    ///
+    /// `Authorization`, `Content-Type` and `Idempotency-Key` are set by the
+    /// client and cannot be added here; use
+    /// [`TelnyxClient::for_managed_account()`](crate::TelnyxClient::for_managed_account)
+    /// to send another API key and [`RequestOptions::idempotency_key`] to
+    /// send an idempotency key. Reserved or invalid names, and invalid
+    /// values, fail the request with
+    /// [`TelnyxError::InvalidRequest`](crate::TelnyxError::InvalidRequest).
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The header name
+    /// * `value` - The header value
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Send the next POST request with an `Idempotency-Key`, making it safe
+    /// to retry
+    ///
+    /// The key applies to a single call and is reused across that call's
+    /// retries. Later POST requests made with these options, or with clones
+    /// of them, fall back to the client's
+    /// [`auto_idempotency_keys`](crate::TelnyxClientBuilder::auto_idempotency_keys)
+    /// setting.
+    pub fn idempotency_key(mut self, key: impl Into<String>) -> Self {
+        self.idempotency_key = Arc::new(Mutex::new(Some(key.into())));
+        self
+    }
+
+    /// Override the client's [`RetryPolicy`]
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Whether a key is set that no call has sent yet
+    pub(crate) fn has_idempotency_key(&self) -> bool {
+        self.idempotency_key
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .is_some()
+    }
+
+    /// Take the idempotency key so that only one call sends it
+    pub(crate) fn take_idempotency_key(&self) -> Option<String> {
+        self.idempotency_key
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .take()
+    }
+}
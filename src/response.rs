@@ -0,0 +1,94 @@
+use reqwest::{StatusCode, header::HeaderMap};
+use std::time::Duration;
+
+/// Header carrying the ID Telnyx support uses to trace a request
+pub(crate) const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Metadata about the HTTP response an API result was read from
+///
+/// Available on [`ApiResponse`](crate::models::ApiResponse) and
+/// [`ApiListResponse`](crate::models::ApiListResponse) through their
+/// `response_meta` field.
+///
+/// # Example
+///
+/// ```no_run
+/// # use telnyx_rs::TelnyxClient;
+/// # async fn example(client: &TelnyxClient) -> Result<(), telnyx_rs::TelnyxError> {
+/// let address = client.addresses().get("1234567890").await?;
+/// if let Some(meta) = &address.response_meta {
+///     println!("request {:?} took {:?}", meta.request_id, meta.latency);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ResponseMeta {
+    /// HTTP status code of the response
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// The `x-request-id` header, if present
+    pub request_id: Option<String>,
+    /// Time between sending the final attempt and reading the full body
+    pub latency: Duration,
+    /// Number of attempts made, including retries
+    pub attempts: u32,
+}
+
+/// Rate limit state reported by the `x-ratelimit-*` response headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests allowed in the current window
+    pub limit: Option<u64>,
+    /// Requests remaining in the current window
+    pub remaining: Option<u64>,
+    /// Seconds until the current window resets
+    pub reset: Option<u64>,
+}
+
+impl ResponseMeta {
+    pub(crate) fn new(
+        status: StatusCode,
+        headers: HeaderMap,
+        latency: Duration,
+        attempts: u32,
+    ) -> Self {
+        let request_id = header_str(&headers, REQUEST_ID_HEADER).map(str::to_string);
+
+        Self {
+            status,
+            headers,
+            request_id,
+            latency,
+            attempts,
+        }
+    }
+
+    /// A response header as a string, if present and valid UTF-8
+    pub fn header(&self, name: &str) -> Option<&str> {
+        header_str(&self.headers, name)
+    }
+
+    /// Rate limit state, if the response carried any `x-ratelimit-*` headers
+    pub fn rate_limit(&self) -> Option<RateLimit> {
+        let parse = |name| self.header(name).and_then(|v| v.trim().parse().ok());
+        let rate_limit = RateLimit {
+            limit: parse("x-ratelimit-limit"),
+            remaining: parse("x-ratelimit-remaining"),
+            reset: parse("x-ratelimit-reset"),
+        };
+
+        (rate_limit.limit.is_some() || rate_limit.remaining.is_some() || rate_limit.reset.is_some())
+            .then_some(rate_limit)
+    }
+}
+
+/// Implemented by response envelopes that carry [`ResponseMeta`]
+pub(crate) trait WithResponseMeta {
+    fn set_response_meta(&mut self, meta: ResponseMeta);
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
@@ -0,0 +1,111 @@
+use bon::Builder;
+use chrono::{DateTime, Utc};
+use reqwest::{
+    Method, StatusCode,
+    header::{HeaderMap, RETRY_AFTER},
+};
+use std::time::Duration;
+
+/// Header that marks a non-idempotent request as safe to retry
+pub(crate) const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+
+/// Retry policy applied to every request sent by a [`TelnyxClient`](crate::TelnyxClient)
+///
+/// Requests are retried on 5xx and 429 responses, and on timeouts or
+/// connection failures. Only idempotent verbs (GET, PUT, DELETE) are retried
+/// unless the request carries an `Idempotency-Key` header.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use telnyx_rs::{RetryPolicy, TelnyxClient};
+///
+/// # fn example() -> Result<(), telnyx_rs::TelnyxError> {
+/// let client = TelnyxClient::builder()
+///     .api_key("your-api-key")
+///     .retry_policy(
+///         RetryPolicy::builder()
+///             .max_attempts(5)
+///             .base_delay(Duration::from_millis(200))
+///             .build(),
+///     )
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Builder)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one (defaults to 3)
+    #[builder(default = 3)]
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on every subsequent retry (defaults to 500ms)
+    #[builder(default = Duration::from_millis(500))]
+    pub base_delay: Duration,
+    /// Upper bound for a single delay, including `Retry-After` values (defaults to 30 seconds)
+    #[builder(default = Duration::from_secs(30))]
+    pub max_delay: Duration,
+    /// Randomize delays to avoid synchronized retries across clients (defaults to true)
+    #[builder(default = true)]
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn disabled() -> Self {
+        Self::builder().max_attempts(1).build()
+    }
+
+    /// Whether a request with the given method and headers may be sent more than once
+    pub(crate) fn is_retry_safe(method: &Method, headers: &HeaderMap) -> bool {
+        matches!(*method, Method::GET | Method::PUT | Method::DELETE)
+            || headers.contains_key(IDEMPOTENCY_KEY_HEADER)
+    }
+
+    /// Whether a response status should be retried
+    pub(crate) fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// The delay before the given retry (1 for the first retry)
+    ///
+    /// A `Retry-After` value takes precedence over the computed backoff. Both
+    /// are capped at `max_delay`.
+    pub(crate) fn delay(&self, retry: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after.min(self.max_delay);
+        }
+
+        let factor = 2u32.saturating_pow(retry.saturating_sub(1));
+        let delay = self.base_delay.saturating_mul(factor).min(self.max_delay);
+
+        if self.jitter {
+            let half = delay / 2;
+            half + half.mul_f64(fastrand::f64())
+        } else {
+            delay
+        }
+    }
+}
+
+/// Parse a `Retry-After` header given either in seconds or as an HTTP date
+pub(crate) fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
@@ -0,0 +1,89 @@
+use bytes::Bytes;
+use reqwest::Method;
+use std::time::Instant;
+use tracing::{Span, field::Empty};
+
+use crate::{error::TelnyxError, response::ResponseMeta};
+
+/// Open the span covering a request and all of its retries
+///
+/// Only the method and a path template are recorded up front. Path segments
+/// that contain digits are treated as resource IDs and replaced with `{id}`
+/// to keep span cardinality low.
+pub(crate) fn request_span(method: &Method, path: &str) -> Span {
+    tracing::info_span!(
+        "telnyx.request",
+        http.method = %method,
+        http.path = %path_template(path),
+        http.status = Empty,
+        attempt = Empty,
+        duration_ms = Empty,
+        attempt_latency_ms = Empty,
+        request_id = Empty,
+    )
+}
+
+/// Record the outcome of a request on its span
+///
+/// `duration_ms` covers the whole call since `started`, including retries,
+/// backoff and rate limit waits. `attempt_latency_ms` covers only the
+/// attempt that produced the response.
+pub(crate) fn record_result(
+    span: &Span,
+    started: Instant,
+    result: &Result<(ResponseMeta, Bytes), TelnyxError>,
+) {
+    span.record("duration_ms", started.elapsed().as_millis() as u64);
+    match result {
+        Ok((meta, body)) => {
+            span.record("http.status", meta.status.as_u16());
+            span.record("attempt_latency_ms", meta.latency.as_millis() as u64);
+            if let Some(request_id) = &meta.request_id {
+                span.record("request_id", request_id.as_str());
+            }
+            // Bodies carry message text, phone numbers and credentials, so
+            // only their size is logged.
+            if meta.status.is_client_error() || meta.status.is_server_error() {
+                tracing::warn!(
+                    parent: span,
+                    http.status = meta.status.as_u16(),
+                    body_bytes = body.len(),
+                    "request returned an error status"
+                );
+            } else {
+                tracing::debug!(parent: span, body_bytes = body.len(), "request completed");
+            }
+        }
+        Err(error) => {
+            // Error messages can embed the response body, which must stay
+            // below INFO, so only the error kind is logged here.
+            tracing::warn!(parent: span, error = error_kind(error), "request failed");
+        }
+    }
+}
+
+fn error_kind(error: &TelnyxError) -> &'static str {
+    match error {
+        TelnyxError::Http(_) => "http",
+        TelnyxError::Timeout(_) => "timeout",
+        TelnyxError::Connect(_) => "connect",
+        TelnyxError::Api { .. } => "api",
+        TelnyxError::Parse(_) => "parse",
+        TelnyxError::Config(_) => "config",
+        TelnyxError::InvalidRequest(_) => "invalid_request",
+        TelnyxError::CircuitOpen { .. } => "circuit_open",
+    }
+}
+
+fn path_template(path: &str) -> String {
+    path.split('/')
+        .map(|segment| {
+            if segment.bytes().any(|b| b.is_ascii_digit()) {
+                "{id}"
+            } else {
+                segment
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
@@ -0,0 +1,133 @@
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use reqwest::{Client, Method, StatusCode, Url, header::HeaderMap};
+use std::time::Duration;
+
+use crate::error::TelnyxError;
+
+/// A fully prepared HTTP request handed to an [`HttpTransport`]
+#[derive(Debug, Clone)]
+pub struct TransportRequest {
+    /// HTTP method
+    pub method: Method,
+    /// Absolute URL, including the query string
+    pub url: Url,
+    /// Request headers, including authentication
+    pub headers: HeaderMap,
+    /// JSON request body, if any
+    pub body: Option<Bytes>,
+    /// Timeout for this request, overriding the transport's default
+    pub timeout: Option<Duration>,
+}
+
+/// The HTTP response returned by an [`HttpTransport`]
+#[derive(Debug, Clone, Default)]
+pub struct TransportResponse {
+    /// HTTP status code
+    pub status: StatusCode,
+    /// Response headers
+    pub headers: HeaderMap,
+    /// Full response body
+    pub body: Bytes,
+}
+
+/// Sends HTTP requests on behalf of a [`TelnyxClient`](crate::TelnyxClient)
+///
+/// The client handles authentication, retries and error parsing, so a
+/// transport only needs to perform a single exchange. Non-2xx responses must
+/// be returned as `Ok`; errors are reserved for failures to get a response
+/// at all.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures::future::BoxFuture;
+/// use telnyx_rs::{
+///     HttpTransport, StatusCode, TelnyxClient, TelnyxError, TransportRequest, TransportResponse,
+/// };
+///
+/// struct AlwaysNotFound;
+///
+/// impl HttpTransport for AlwaysNotFound {
+///     fn send(
+///         &self,
+///         _request: TransportRequest,
+///     ) -> BoxFuture<'_, Result<TransportResponse, TelnyxError>> {
+///         Box::pin(async {
+///             Ok(TransportResponse {
+///                 status: StatusCode::NOT_FOUND,
+///                 ..Default::default()
+///             })
+///         })
+///     }
+/// }
+///
+/// # fn example() -> Result<(), TelnyxError> {
+/// let client = TelnyxClient::builder()
+///     .api_key("your-api-key")
+///     .transport(AlwaysNotFound)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub trait HttpTransport: Send + Sync {
+    /// Send a request and read the full response
+    fn send(
+        &self,
+        request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, TelnyxError>>;
+}
+
+/// The default [`HttpTransport`], backed by a [`reqwest::Client`]
+#[derive(Debug, Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Wrap a preconfigured reqwest client
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl From<Client> for ReqwestTransport {
+    fn from(client: Client) -> Self {
+        Self::new(client)
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send(
+        &self,
+        request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, TelnyxError>> {
+        Box::pin(async move {
+            let mut builder = self
+                .client
+                .request(request.method, request.url)
+                .headers(request.headers);
+            if let Some(body) = request.body {
+                builder = builder.body(body);
+            }
+            if let Some(timeout) = request.timeout {
+                builder = builder.timeout(timeout);
+            }
+
+            let response = builder.send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = if status.is_success() {
+                response.bytes().await?
+            } else {
+                response.bytes().await.unwrap_or_default()
+            };
+
+            Ok(TransportResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
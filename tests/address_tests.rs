@@ -1,12 +1,12 @@
 mod common;
 
 use telnyx_rs::models::{
-    AddressAcceptSuggestionRequest, AddressValidationStatus, CreateAddressRequest,
-    ValidateAddressRequest,
+    AddressAcceptSuggestionRequest, AddressSort, AddressValidationStatus, CreateAddressRequest,
+    ListAddressesParams, ValidateAddressRequest,
 };
 use wiremock::{
     Mock, ResponseTemplate,
-    matchers::{bearer_token, body_json, method, path},
+    matchers::{bearer_token, body_json, method, path, query_param},
 };
 
 mod responses {
@@ -73,6 +73,17 @@ mod responses {
         })
     }
 
+    pub fn validation_error_response() -> serde_json::Value {
+        serde_json::json!({
+            "errors": [{
+                "code": "10015",
+                "title": "Invalid country code",
+                "detail": "The country code XX is not supported.",
+                "source": { "pointer": "/country_code" }
+            }]
+        })
+    }
+
     pub fn accept_suggestion_response(id: &str) -> serde_json::Value {
         serde_json::json!({
             "data": {
@@ -101,7 +112,7 @@ async fn create_address_sucess() {
     let expected_response = responses::address_response(123456);
 
     Mock::given(method("POST"))
-        .and(path("/address"))
+        .and(path("/addresses"))
         .and(bearer_token("test-api-key"))
         .and(body_json(&request))
         .respond_with(ResponseTemplate::new(200).set_body_json(&expected_response))
@@ -131,7 +142,7 @@ async fn create_address_unauthorized() {
     let ctx = common::setup().await;
 
     Mock::given(method("POST"))
-        .and(path("/address"))
+        .and(path("/addresses"))
         .respond_with(ResponseTemplate::new(401))
         .expect(1)
         .mount(&ctx.server)
@@ -160,7 +171,7 @@ async fn create_address_unprocessable() {
     let ctx = common::setup().await;
 
     Mock::given(method("POST"))
-        .and(path("/address"))
+        .and(path("/addresses"))
         .respond_with(ResponseTemplate::new(422))
         .expect(1)
         .mount(&ctx.server)
@@ -183,13 +194,85 @@ async fn create_address_unprocessable() {
     ));
 }
 
+#[tokio::test]
+async fn create_address_validation_errors() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(
+            ResponseTemplate::new(422).set_body_json(responses::validation_error_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St".to_string())
+        .locality("Chicago".to_string())
+        .country_code("XX".to_string())
+        .build();
+
+    // Act
+    let result = ctx.client.addresses().create(request).await;
+
+    // Assert
+    let error = result.unwrap_err();
+    let errors = error.api_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].code, "10015");
+    assert_eq!(errors[0].title, "Invalid country code");
+    assert_eq!(
+        errors[0].source.as_ref().and_then(|s| s.pointer.as_deref()),
+        Some("/country_code")
+    );
+}
+
+#[tokio::test]
+async fn create_address_unparseable_error_body() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(500).set_body_string("upstream failure"))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St".to_string())
+        .locality("Chicago".to_string())
+        .country_code("US".to_string())
+        .build();
+
+    // Act
+    let result = ctx.client.addresses().create(request).await;
+
+    // Assert
+    match result.unwrap_err() {
+        telnyx_rs::TelnyxError::Api {
+            status,
+            message,
+            errors,
+            ..
+        } => {
+            assert_eq!(status, 500);
+            assert_eq!(message, "upstream failure");
+            assert!(errors.is_empty());
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
 #[tokio::test]
 async fn get_address_unauthorized() {
     // Arrange
     let ctx = common::setup().await;
 
     Mock::given(method("GET"))
-        .and(path(".address/123"))
+        .and(path("/addresses/123"))
         .respond_with(ResponseTemplate::new(401))
         .expect(1)
         .mount(&ctx.server)
@@ -212,7 +295,7 @@ async fn get_address_not_found() {
     let ctx = common::setup().await;
 
     Mock::given(method("GET"))
-        .and(path(".address/nonexistent"))
+        .and(path("/addresses/nonexistent"))
         .respond_with(ResponseTemplate::new(404))
         .expect(1)
         .mount(&ctx.server)
@@ -221,7 +304,6 @@ async fn get_address_not_found() {
     // Act
     let result = ctx.client.addresses().get("nonexistent").await;
 
-
     // Assert
     assert!(result.is_err());
     assert!(matches!(
@@ -246,7 +328,7 @@ async fn list_addresses_success() {
         .await;
 
     // Act
-    let result = ctx.client.addresses().list().await;
+    let result = ctx.client.addresses().list(None).await;
 
     // Assert
     assert!(result.is_ok());
@@ -260,6 +342,45 @@ async fn list_addresses_success() {
     assert_eq!(meta.page_number, 1);
 }
 
+#[tokio::test]
+async fn list_addresses_with_params() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    let expected_response = responses::address_list_response(&[789]);
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .and(query_param("page[number]", "2"))
+        .and(query_param("page[size]", "50"))
+        .and(query_param("filter[customer_reference][contains]", "acme"))
+        .and(query_param("filter[street_address][contains]", "Superior"))
+        .and(query_param("filter[address_book][eq]", "true"))
+        .and(query_param("filter[used_as_emergency]", "false"))
+        .and(query_param("sort", "-created_at"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&expected_response))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListAddressesParams::builder()
+        .page_number(2)
+        .page_size(50)
+        .customer_reference_contains("acme")
+        .street_address_contains("Superior")
+        .address_book_eq(true)
+        .used_as_emergency(false)
+        .sort(AddressSort::CreatedAtDesc)
+        .build();
+
+    // Act
+    let result = ctx.client.addresses().list(Some(params)).await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().data[0].id, 789);
+}
+
 #[tokio::test]
 async fn list_addresses_empty() {
     // Arrange
@@ -276,7 +397,7 @@ async fn list_addresses_empty() {
         .await;
 
     // Act
-    let result = ctx.client.addresses().list().await;
+    let result = ctx.client.addresses().list(None).await;
 
     // Assert
     assert!(result.is_ok());
@@ -300,7 +421,7 @@ async fn list_addresses_unauthorized() {
         .await;
 
     // Act
-    let result = ctx.client.addresses().list().await;
+    let result = ctx.client.addresses().list(None).await;
 
     // Assert
     assert!(result.is_err());
@@ -316,9 +437,9 @@ async fn delete_address_success() {
     let ctx = common::setup().await;
 
     Mock::given(method("DELETE"))
-        .and(path(".address/123"))
+        .and(path("/addresses/123"))
         .and(bearer_token("test-api-key"))
-        .respond_with(ResponseTemplate::new(200))
+        .respond_with(ResponseTemplate::new(200).insert_header("x-request-id", "req-deleted"))
         .expect(1)
         .mount(&ctx.server)
         .await;
@@ -327,7 +448,7 @@ async fn delete_address_success() {
     let result = ctx.client.addresses().delete("123").await;
 
     // Assert
-    assert!(result.is_ok());
+    assert_eq!(result.unwrap().request_id.as_deref(), Some("req-deleted"));
 }
 
 #[tokio::test]
@@ -336,7 +457,7 @@ async fn delete_address_unauthorized() {
     let ctx = common::setup().await;
 
     Mock::given(method("DELETE"))
-        .and(path(".address/123"))
+        .and(path("/addresses/123"))
         .respond_with(ResponseTemplate::new(401))
         .expect(1)
         .mount(&ctx.server)
@@ -359,7 +480,7 @@ async fn delete_address_not_found() {
     let ctx = common::setup().await;
 
     Mock::given(method("DELETE"))
-        .and(path(".address/nonexistent"))
+        .and(path("/addresses/nonexistent"))
         .respond_with(ResponseTemplate::new(404))
         .expect(1)
         .mount(&ctx.server)
@@ -515,7 +636,11 @@ async fn accept_suggestions_success() {
 
     // Act
     let request = AddressAcceptSuggestionRequest::default();
-    let result = ctx.client.addresses().accept_suggestions("123", request).await;
+    let result = ctx
+        .client
+        .addresses()
+        .accept_suggestions("123", request)
+        .await;
 
     // Assert
     assert!(result.is_ok());
@@ -538,7 +663,11 @@ async fn accept_suggestions_unauthorized() {
 
     // Act
     let request = AddressAcceptSuggestionRequest::default();
-    let result = ctx.client.addresses().accept_suggestions("123", request).await;
+    let result = ctx
+        .client
+        .addresses()
+        .accept_suggestions("123", request)
+        .await;
 
     // Assert
     assert!(result.is_err());
@@ -562,7 +691,11 @@ async fn accept_suggestions_not_found() {
 
     // Act
     let request = AddressAcceptSuggestionRequest::default();
-    let result = ctx.client.addresses().accept_suggestions("nonexistent", request).await;
+    let result = ctx
+        .client
+        .addresses()
+        .accept_suggestions("nonexistent", request)
+        .await;
 
     // Assert
     assert!(result.is_err());
@@ -572,8 +705,6 @@ async fn accept_suggestions_not_found() {
     ));
 }
 
-
-
 #[tokio::test]
 async fn accept_suggestions_unprocessable() {
     // Arrange
@@ -588,7 +719,11 @@ async fn accept_suggestions_unprocessable() {
 
     // Act
     let request = AddressAcceptSuggestionRequest::default();
-    let result = ctx.client.addresses().accept_suggestions("123", request).await;
+    let result = ctx
+        .client
+        .addresses()
+        .accept_suggestions("123", request)
+        .await;
 
     // Assert
     assert!(result.is_err());
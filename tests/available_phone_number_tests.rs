@@ -0,0 +1,187 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{PhoneNumberFeature, PhoneNumberSearchFilter, PhoneNumberType},
+    query::ToQuery,
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+mod responses {
+    pub fn search_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": [{
+                "record_type": "available_phone_number",
+                "phone_number": "+13125550000",
+                "vanity_format": null,
+                "best_effort": false,
+                "quickship": true,
+                "reservable": true,
+                "region_information": [
+                    { "region_type": "country_code", "region_name": "US" },
+                    { "region_type": "rate_center", "region_name": "CHICAGO HEIGHTS" }
+                ],
+                "cost_information": {
+                    "upfront_cost": "1.00",
+                    "monthly_cost": "1.00",
+                    "currency": "USD"
+                },
+                "features": [{ "name": "sms" }, { "name": "voice" }, { "name": "rcs" }]
+            }],
+            "meta": { "total_results": 1, "best_effort_results": 0 }
+        })
+    }
+}
+
+#[test]
+fn search_filter_encodes_every_field() {
+    // Arrange
+    let filter = PhoneNumberSearchFilter::builder()
+        .country_code("US")
+        .locality("Chicago")
+        .administrative_area("IL")
+        .national_destination_code("312")
+        .phone_number_starts_with("312555")
+        .phone_number_ends_with("00")
+        .phone_number_contains("55")
+        .features(vec![PhoneNumberFeature::Sms, PhoneNumberFeature::Emergency])
+        .phone_number_type(PhoneNumberType::TollFree)
+        .best_effort(false)
+        .quickship(true)
+        .reservable(true)
+        .limit(5)
+        .build();
+
+    // Act
+    let query = filter.to_query();
+
+    // Assert
+    let pairs: Vec<(&str, &str)> = query
+        .pairs()
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect();
+    assert_eq!(
+        pairs,
+        vec![
+            ("filter[country_code]", "US"),
+            ("filter[locality]", "Chicago"),
+            ("filter[administrative_area]", "IL"),
+            ("filter[national_destination_code]", "312"),
+            ("filter[phone_number][starts_with]", "312555"),
+            ("filter[phone_number][ends_with]", "00"),
+            ("filter[phone_number][contains]", "55"),
+            ("filter[features][]", "sms"),
+            ("filter[features][]", "emergency"),
+            ("filter[phone_number_type]", "toll_free"),
+            ("filter[best_effort]", "false"),
+            ("filter[quickship]", "true"),
+            ("filter[reservable]", "true"),
+            ("filter[limit]", "5"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn search_available_phone_numbers_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/available_phone_numbers"))
+        .and(query_param("filter[country_code]", "US"))
+        .and(query_param("filter[features][]", "voice"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(responses::search_response()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let filter = PhoneNumberSearchFilter::builder()
+        .country_code("US")
+        .features(vec![PhoneNumberFeature::Voice])
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .available_phone_numbers()
+        .search(Some(filter))
+        .await;
+
+    // Assert
+    let response = result.unwrap();
+    assert_eq!(response.meta.unwrap().total_results, 1);
+    let number = &response.data[0];
+    assert_eq!(number.phone_number, "+13125550000");
+    assert!(number.quickship);
+    assert_eq!(number.region_information[1].region_name, "CHICAGO HEIGHTS");
+    assert_eq!(
+        number.cost_information.as_ref().unwrap().monthly_cost,
+        "1.00"
+    );
+    let features: Vec<PhoneNumberFeature> = number.features.iter().map(|f| f.name).collect();
+    assert_eq!(
+        features,
+        vec![
+            PhoneNumberFeature::Sms,
+            PhoneNumberFeature::Voice,
+            PhoneNumberFeature::Unknown
+        ]
+    );
+}
+
+#[tokio::test]
+async fn search_available_phone_numbers_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/available_phone_numbers"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.available_phone_numbers().search(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn search_available_phone_numbers_bad_request() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/available_phone_numbers"))
+        .and(query_param("filter[country_code]", "ZZ"))
+        .respond_with(ResponseTemplate::new(400))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let filter = PhoneNumberSearchFilter::builder()
+        .country_code("ZZ")
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .available_phone_numbers()
+        .search(Some(filter))
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 400, .. }
+    ));
+}
@@ -0,0 +1,215 @@
+#![cfg(feature = "blocking")]
+
+use std::time::{Duration, Instant};
+
+use telnyx_rs::{Method, blocking::TelnyxClient, models::CreateAddressRequest};
+use tokio::runtime::Runtime;
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{bearer_token, method, path, query_param},
+};
+
+struct BlockingContext {
+    runtime: Runtime,
+    server: MockServer,
+    client: TelnyxClient,
+}
+
+impl BlockingContext {
+    fn mount(&self, mock: Mock) {
+        self.runtime.block_on(mock.mount(&self.server));
+    }
+}
+
+fn setup() -> BlockingContext {
+    let runtime = Runtime::new().expect("Failed to start test runtime");
+    let server = runtime.block_on(MockServer::start());
+    let client = TelnyxClient::builder()
+        .api_key("test-api-key")
+        .base_url(server.uri())
+        .build_blocking()
+        .expect("Failed to build test client");
+
+    BlockingContext {
+        runtime,
+        server,
+        client,
+    }
+}
+
+fn address(id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "record_type": "address",
+        "street_address": "311 W Superior St",
+        "locality": "Chicago",
+        "country_code": "US",
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    })
+}
+
+#[test]
+fn blocking_create_address() {
+    // Arrange
+    let ctx = setup();
+    ctx.mount(
+        Mock::given(method("POST"))
+            .and(path("/addresses"))
+            .and(bearer_token("test-api-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": address(123)
+            })))
+            .expect(1),
+    );
+
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St")
+        .locality("Chicago")
+        .country_code("US")
+        .build();
+
+    // Act
+    let result = ctx.client.addresses().create(request);
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, 123);
+}
+
+#[test]
+fn blocking_get_address_not_found() {
+    // Arrange
+    let ctx = setup();
+    ctx.mount(
+        Mock::given(method("GET"))
+            .and(path("/addresses/123"))
+            .respond_with(ResponseTemplate::new(404)),
+    );
+
+    // Act
+    let result = ctx.client.addresses().get("123");
+
+    // Assert
+    assert!(result.unwrap_err().is_not_found());
+}
+
+#[test]
+fn blocking_list_all_iterates_pages() {
+    // Arrange
+    let ctx = setup();
+    for page_number in 1..=2 {
+        ctx.mount(
+            Mock::given(method("GET"))
+                .and(path("/addresses"))
+                .and(query_param("page[number]", page_number.to_string()))
+                .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "data": [address(page_number)],
+                    "meta": {
+                        "total_pages": 2,
+                        "total_results": 2,
+                        "page_number": page_number,
+                        "page_size": 1
+                    }
+                }))),
+        );
+    }
+
+    // Act
+    let result = ctx.client.addresses().list_all(None).collect_all();
+
+    // Assert
+    let ids: Vec<i64> = result.unwrap().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[test]
+fn blocking_list_all_stops_after_max_items() {
+    // Arrange
+    let ctx = setup();
+    ctx.mount(
+        Mock::given(method("GET"))
+            .and(path("/addresses"))
+            .and(query_param("page[number]", "1"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [address(1), address(2)],
+                "meta": {
+                    "total_pages": 2,
+                    "total_results": 4,
+                    "page_number": 1,
+                    "page_size": 2
+                }
+            })))
+            .expect(1),
+    );
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list_all(None)
+        .max_items(1)
+        .collect_all();
+
+    // Assert
+    let ids: Vec<i64> = result.unwrap().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![1]);
+}
+
+#[test]
+fn blocking_raw_request() {
+    // Arrange
+    let ctx = setup();
+    ctx.mount(
+        Mock::given(method("GET"))
+            .and(path("/balance"))
+            .and(query_param("currency", "USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": { "balance": "300.00" }
+            })))
+            .expect(1),
+    );
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::GET, "/balance")
+        .query(&[("currency", "USD")])
+        .send::<serde_json::Value>();
+
+    // Assert
+    assert_eq!(result.unwrap()["data"]["balance"], "300.00");
+}
+
+#[test]
+fn blocking_calls_from_several_threads_run_concurrently() {
+    // Arrange
+    let ctx = setup();
+    ctx.mount(
+        Mock::given(method("GET"))
+            .and(path("/addresses/123"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "data": address(123) }))
+                    .set_delay(Duration::from_millis(300)),
+            ),
+    );
+
+    // Act
+    let started = Instant::now();
+    let results: Vec<_> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let addresses = ctx.client.addresses();
+                scope.spawn(move || addresses.get("123"))
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    // Assert
+    assert!(results.iter().all(Result::is_ok));
+    assert!(started.elapsed() < Duration::from_millis(1000));
+}
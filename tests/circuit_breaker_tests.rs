@@ -0,0 +1,281 @@
+mod common;
+
+use std::time::Duration;
+
+use telnyx_rs::{
+    CircuitBreakerPolicy, CircuitState, Method, Quota, RateLimitPolicy, RetryPolicy, TelnyxClient,
+    TelnyxError,
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn policy(open_duration: Duration) -> CircuitBreakerPolicy {
+    CircuitBreakerPolicy::builder()
+        .failure_rate_threshold(0.5)
+        .window_size(4)
+        .minimum_requests(2)
+        .open_duration(open_duration)
+        .build()
+}
+
+async fn setup(open_duration: Duration) -> common::TestContext {
+    common::setup_with(|builder| {
+        builder
+            .retry_policy(RetryPolicy::disabled())
+            .circuit_breaker(policy(open_duration))
+    })
+    .await
+}
+
+#[tokio::test]
+async fn circuit_opens_after_failures_and_fails_fast() {
+    // Arrange
+    let ctx = setup(Duration::from_secs(60)).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let first = ctx.client.addresses().delete("123").await;
+    let state_after_one = ctx.client.circuit_state();
+    let second = ctx.client.addresses().delete("123").await;
+    let rejected = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert_eq!(first.unwrap_err().status(), Some(503));
+    assert_eq!(state_after_one, Some(CircuitState::Closed));
+    assert_eq!(second.unwrap_err().status(), Some(503));
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Open));
+    match rejected {
+        Err(TelnyxError::CircuitOpen { retry_after }) => {
+            assert!(retry_after > Duration::from_secs(50));
+        }
+        other => panic!("expected CircuitOpen, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn successful_probe_closes_circuit() {
+    // Arrange
+    let ctx = setup(Duration::from_millis(50)).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(500))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    for _ in 0..2 {
+        let _ = ctx.client.addresses().delete("123").await;
+    }
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Open));
+
+    // Act
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let state_before_probe = ctx.client.circuit_state();
+    let probe = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert_eq!(state_before_probe, Some(CircuitState::HalfOpen));
+    assert!(probe.is_ok());
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Closed));
+}
+
+#[tokio::test]
+async fn failed_probe_reopens_circuit() {
+    // Arrange
+    let ctx = setup(Duration::from_millis(50)).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&ctx.server)
+        .await;
+
+    for _ in 0..2 {
+        let _ = ctx.client.addresses().delete("123").await;
+    }
+
+    // Act
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    let probe = ctx.client.addresses().delete("123").await;
+    let rejected = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert_eq!(probe.unwrap_err().status(), Some(503));
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Open));
+    assert!(matches!(rejected, Err(TelnyxError::CircuitOpen { .. })));
+}
+
+#[tokio::test]
+async fn rate_limit_wait_does_not_hold_probe() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .retry_policy(RetryPolicy::disabled())
+            .circuit_breaker(policy(Duration::from_millis(50)))
+            .rate_limit(
+                RateLimitPolicy::new().group("balance", Quota::new(1, Duration::from_millis(400))),
+            )
+    })
+    .await;
+
+    Mock::given(method("GET"))
+        .and(path("/balance"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Use the only balance token, then open the circuit with one failure
+    // out of two requests.
+    ctx.client
+        .request(Method::GET, "/balance")
+        .send_empty()
+        .await
+        .unwrap();
+    let _ = ctx.client.addresses().delete("123").await;
+    tokio::time::sleep(Duration::from_millis(60)).await;
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::HalfOpen));
+
+    // Act
+    let waiting = tokio::spawn({
+        let client = ctx.client.clone();
+        async move { client.request(Method::GET, "/balance").send_empty().await }
+    });
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    let probe = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(probe.is_ok());
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Closed));
+    assert!(waiting.await.unwrap().is_ok());
+}
+
+#[tokio::test]
+async fn client_errors_do_not_open_circuit() {
+    // Arrange
+    let ctx = setup(Duration::from_secs(60)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(4)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    for _ in 0..4 {
+        let result = ctx.client.addresses().get("123").await;
+        assert!(result.unwrap_err().is_not_found());
+    }
+
+    // Assert
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Closed));
+}
+
+#[tokio::test]
+async fn rate_limited_responses_are_not_counted() {
+    // Arrange
+    let ctx = setup(Duration::from_secs(60)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses/456"))
+        .respond_with(ResponseTemplate::new(429))
+        .expect(3)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let _ = ctx.client.addresses().get("123").await;
+    for _ in 0..3 {
+        let result = ctx.client.addresses().get("456").await;
+        assert!(result.unwrap_err().is_rate_limited());
+    }
+    let _ = ctx.client.addresses().get("123").await;
+
+    // Assert
+    assert_eq!(ctx.client.circuit_state(), Some(CircuitState::Open));
+}
+
+#[tokio::test]
+async fn circuit_state_is_none_when_disabled() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    // Assert
+    assert_eq!(ctx.client.circuit_state(), None);
+}
+
+#[test]
+fn invalid_policies_are_rejected() {
+    // Arrange
+    let build = |policy| {
+        TelnyxClient::builder()
+            .api_key("test-api-key")
+            .circuit_breaker(policy)
+            .build()
+    };
+
+    // Act
+    let zero_threshold = build(
+        CircuitBreakerPolicy::builder()
+            .failure_rate_threshold(0.0)
+            .build(),
+    );
+    let minimum_exceeds_window = build(
+        CircuitBreakerPolicy::builder()
+            .window_size(5)
+            .minimum_requests(10)
+            .build(),
+    );
+    let no_probes = build(CircuitBreakerPolicy::builder().half_open_probes(0).build());
+    let huge_open_duration = build(
+        CircuitBreakerPolicy::builder()
+            .open_duration(Duration::MAX)
+            .build(),
+    );
+
+    // Assert
+    assert!(matches!(zero_threshold, Err(TelnyxError::Config(_))));
+    assert!(matches!(
+        minimum_exceeds_window,
+        Err(TelnyxError::Config(_))
+    ));
+    assert!(matches!(no_probes, Err(TelnyxError::Config(_))));
+    assert!(matches!(huge_open_duration, Err(TelnyxError::Config(_))));
+}
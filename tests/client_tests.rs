@@ -0,0 +1,45 @@
+mod common;
+
+use telnyx_rs::{Paginator, TelnyxClient, endpoints::AddressApi, models::Address};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+
+#[test]
+fn client_and_handles_are_shareable() {
+    assert_shareable::<TelnyxClient>();
+    assert_shareable::<AddressApi>();
+}
+
+#[test]
+fn paginator_can_be_sent_to_tasks() {
+    fn assert_send<T: Send + 'static>() {}
+    assert_send::<Paginator<'static, Address>>();
+}
+
+#[tokio::test]
+async fn handles_can_be_moved_into_spawned_tasks() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+
+    let addresses = ctx.client.addresses();
+    let client = ctx.client.clone();
+
+    // Act
+    let from_handle = tokio::spawn(async move { addresses.delete("123").await });
+    let from_clone = tokio::spawn(async move { client.addresses().delete("123").await });
+
+    // Assert
+    assert!(from_handle.await.unwrap().is_ok());
+    assert!(from_clone.await.unwrap().is_ok());
+}
@@ -1,4 +1,6 @@
-use telnyx_rs::TelnyxClient;
+#![allow(dead_code)]
+
+use telnyx_rs::{TelnyxClient, TelnyxClientBuilder};
 use wiremock::MockServer;
 
 pub struct TestContext {
@@ -7,10 +9,17 @@ pub struct TestContext {
 }
 
 pub async fn setup() -> TestContext {
+    setup_with(|builder| builder).await
+}
+
+pub async fn setup_with(
+    configure: impl FnOnce(TelnyxClientBuilder) -> TelnyxClientBuilder,
+) -> TestContext {
     let server = MockServer::start().await;
-    let client = TelnyxClient::builder()
+    let builder = TelnyxClient::builder()
         .api_key("test-api-key")
-        .base_url(server.uri())
+        .base_url(server.uri());
+    let client = configure(builder)
         .build()
         .expect("Failed to build test client");
 
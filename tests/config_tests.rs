@@ -0,0 +1,197 @@
+mod common;
+
+use std::collections::HashMap;
+
+use telnyx_rs::{ClientConfig, TelnyxClient, TelnyxError};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{bearer_token, method, path},
+};
+
+const PROFILES: &str = r#"
+[profiles.production]
+api_key = "production-key"
+
+[profiles.staging]
+api_key = "staging-key"
+base_url = "https://staging.example.com/v2"
+timeout_secs = 10
+
+[profiles.staging.retry]
+max_attempts = 5
+base_delay_ms = 200
+"#;
+
+#[test]
+fn profile_is_loaded_from_toml() {
+    // Act
+    let config = ClientConfig::from_profile_str(PROFILES, "staging").unwrap();
+
+    // Assert
+    assert_eq!(config.api_key.as_deref(), Some("staging-key"));
+    assert_eq!(
+        config.base_url.as_deref(),
+        Some("https://staging.example.com/v2")
+    );
+    assert_eq!(config.timeout_secs, Some(10));
+    assert_eq!(config.retry.max_attempts, Some(5));
+    assert_eq!(config.retry.base_delay_ms, Some(200));
+    assert_eq!(config.retry.max_delay_ms, None);
+}
+
+#[test]
+fn missing_profile_is_a_config_error() {
+    // Act
+    let result = ClientConfig::from_profile_str(PROFILES, "qa");
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
+
+#[test]
+fn unknown_profile_keys_are_rejected() {
+    // Act
+    let result = ClientConfig::from_profile_str("[profiles.x]\napi_kye = \"typo\"\n", "x");
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
+
+#[test]
+fn merge_prefers_the_overlay() {
+    // Arrange
+    let base = ClientConfig::from_profile_str(PROFILES, "staging").unwrap();
+    let overlay = ClientConfig {
+        api_key: Some("override-key".into()),
+        ..Default::default()
+    };
+
+    // Act
+    let config = base.merge(overlay);
+
+    // Assert
+    assert_eq!(config.api_key.as_deref(), Some("override-key"));
+    assert_eq!(config.timeout_secs, Some(10));
+}
+
+#[test]
+fn debug_output_redacts_api_key() {
+    // Arrange
+    let config = ClientConfig::from_profile_str(PROFILES, "staging").unwrap();
+
+    // Act
+    let debug = format!("{config:?}");
+
+    // Assert
+    assert!(!debug.contains("staging-key"));
+    assert!(debug.contains("[REDACTED]"));
+    assert!(debug.contains("https://staging.example.com/v2"));
+}
+
+#[tokio::test]
+async fn builder_applies_config() {
+    // Arrange
+    let server = MockServer::start().await;
+    let config = ClientConfig {
+        api_key: Some("config-key".into()),
+        base_url: Some(server.uri()),
+        ..Default::default()
+    };
+    let client = TelnyxClient::builder().config(config).build().unwrap();
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("config-key"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Act
+    let result = client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn from_profile_builds_a_client() {
+    // Arrange
+    let server = MockServer::start().await;
+    let file = std::env::temp_dir().join(format!("telnyx-profile-{}.toml", std::process::id()));
+    std::fs::write(
+        &file,
+        format!(
+            "[profiles.local]\napi_key = \"local-key\"\nbase_url = \"{}\"\n",
+            server.uri()
+        ),
+    )
+    .unwrap();
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("local-key"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Act
+    let client = TelnyxClient::from_profile(&file, "local").unwrap();
+    let result = client.addresses().delete("123").await;
+
+    // Assert
+    std::fs::remove_file(&file).unwrap();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn from_env_reads_variables_and_profiles() {
+    // Arrange
+    let server = MockServer::start().await;
+    let file = std::env::temp_dir().join(format!("telnyx-env-{}.toml", std::process::id()));
+    std::fs::write(
+        &file,
+        format!(
+            "[profiles.mock]\napi_key = \"profile-key\"\nbase_url = \"{}\"\n",
+            server.uri()
+        ),
+    )
+    .unwrap();
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("env-key"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut vars = HashMap::from([("TELNYX_TIMEOUT_SECS", "soon".to_string())]);
+    assert!(matches!(
+        TelnyxClient::from_env_with(|name| vars.get(name).cloned()),
+        Err(TelnyxError::Config(_))
+    ));
+
+    vars.insert("TELNYX_TIMEOUT_SECS", "5".to_string());
+    assert!(matches!(
+        TelnyxClient::from_env_with(|name| vars.get(name).cloned()),
+        Err(TelnyxError::Config(_))
+    ));
+
+    vars.insert("TELNYX_PROFILE", "mock".to_string());
+    vars.insert("TELNYX_CONFIG_FILE", file.display().to_string());
+    vars.insert("TELNYX_API_KEY", "env-key".to_string());
+    vars.insert("TELNYX_RETRY_MAX_ATTEMPTS", "1".to_string());
+
+    // Act
+    let config = ClientConfig::from_env_with(|name| vars.get(name).cloned()).unwrap();
+    let client = TelnyxClient::from_env_with(|name| vars.get(name).cloned()).unwrap();
+    let result = client.addresses().delete("123").await;
+
+    // Assert
+    std::fs::remove_file(&file).unwrap();
+    assert_eq!(config.timeout_secs, Some(5));
+    assert_eq!(config.retry.max_attempts, Some(1));
+    assert!(result.is_ok());
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use std::time::Duration;
+
+use telnyx_rs::{HttpVersion, TelnyxClient, TelnyxError};
+use wiremock::{
+    Mock, MockServer, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+fn builder() -> telnyx_rs::TelnyxClientBuilder {
+    TelnyxClient::builder().api_key("test-api-key")
+}
+
+#[tokio::test]
+async fn connection_settings_are_applied() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .timeout(Duration::from_secs(10))
+            .connect_timeout(Duration::from_secs(2))
+            .read_timeout(Duration::from_secs(5))
+            .pool_idle_timeout(Duration::from_secs(30))
+            .pool_max_idle_per_host(4)
+            .tcp_keepalive(Duration::from_secs(60))
+            .http_version(HttpVersion::Http1Only)
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn user_agent_includes_suffix() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.user_agent_suffix("my-app/1.2")).await;
+    let expected = format!("telnyx-rs/{} my-app/1.2", env!("CARGO_PKG_VERSION"));
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(header("user-agent", expected.as_str()))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn requests_are_sent_through_proxy() {
+    // Arrange
+    let proxy = MockServer::start().await;
+    let client = builder()
+        .base_url("http://api.telnyx.invalid/v2")
+        .proxy(proxy.uri())
+        .build()
+        .unwrap();
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/addresses/123"))
+        .and(header("host", "api.telnyx.invalid"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    // Act
+    let result = client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn invalid_connection_settings_are_rejected() {
+    // Act
+    let zero_timeout = builder().connect_timeout(Duration::ZERO).build();
+    let connect_exceeds_total = builder()
+        .timeout(Duration::from_secs(5))
+        .connect_timeout(Duration::from_secs(10))
+        .build();
+    let read_exceeds_default = builder().read_timeout(Duration::from_secs(60)).build();
+    let invalid_suffix = builder().user_agent_suffix("bad\nagent").build();
+    let invalid_proxy = builder().proxy("not a url").build();
+
+    // Assert
+    assert!(matches!(zero_timeout, Err(TelnyxError::Config(_))));
+    assert!(matches!(connect_exceeds_total, Err(TelnyxError::Config(_))));
+    assert!(matches!(read_exceeds_default, Err(TelnyxError::Config(_))));
+    assert!(matches!(invalid_suffix, Err(TelnyxError::Config(_))));
+    assert!(matches!(invalid_proxy, Err(TelnyxError::Config(_))));
+}
+
+#[test]
+fn connection_settings_conflict_with_custom_http_client() {
+    // Act
+    let result = builder()
+        .http_client(reqwest::Client::new())
+        .pool_max_idle_per_host(0)
+        .build();
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
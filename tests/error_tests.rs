@@ -0,0 +1,119 @@
+mod common;
+
+use std::time::Duration;
+
+use telnyx_rs::{RetryPolicy, TelnyxClient, TelnyxError};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+async fn error_for_status(status: u16) -> TelnyxError {
+    let ctx = common::setup_with(|builder| builder.retry_policy(RetryPolicy::disabled())).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(status))
+        .mount(&ctx.server)
+        .await;
+
+    ctx.client.addresses().get("123").await.unwrap_err()
+}
+
+#[tokio::test]
+async fn rate_limited_error() {
+    // Act
+    let error = error_for_status(429).await;
+
+    // Assert
+    assert!(error.is_rate_limited());
+    assert!(error.is_retryable());
+    assert!(!error.is_auth());
+}
+
+#[tokio::test]
+async fn server_error_is_retryable() {
+    // Act
+    let error = error_for_status(503).await;
+
+    // Assert
+    assert!(error.is_retryable());
+    assert!(!error.is_rate_limited());
+    assert_eq!(error.status(), Some(503));
+}
+
+#[tokio::test]
+async fn auth_errors() {
+    // Act
+    let unauthorized = error_for_status(401).await;
+    let forbidden = error_for_status(403).await;
+
+    // Assert
+    assert!(unauthorized.is_auth());
+    assert!(forbidden.is_auth());
+    assert!(!unauthorized.is_retryable());
+}
+
+#[tokio::test]
+async fn not_found_error() {
+    // Act
+    let error = error_for_status(404).await;
+
+    // Assert
+    assert!(error.is_not_found());
+    assert!(!error.is_validation());
+}
+
+#[tokio::test]
+async fn validation_errors() {
+    // Act
+    let bad_request = error_for_status(400).await;
+    let unprocessable = error_for_status(422).await;
+
+    // Assert
+    assert!(bad_request.is_validation());
+    assert!(unprocessable.is_validation());
+    assert!(!unprocessable.is_retryable());
+}
+
+#[tokio::test]
+async fn timeout_error() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .timeout(Duration::from_millis(50))
+            .retry_policy(RetryPolicy::disabled())
+    })
+    .await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200).set_delay(Duration::from_millis(500)))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let error = ctx.client.addresses().get("123").await.unwrap_err();
+
+    // Assert
+    assert!(matches!(error, TelnyxError::Timeout(_)));
+    assert!(error.is_retryable());
+}
+
+#[tokio::test]
+async fn connect_error() {
+    // Arrange
+    let client = TelnyxClient::builder()
+        .api_key("test-api-key")
+        .base_url("http://127.0.0.1:1")
+        .retry_policy(RetryPolicy::disabled())
+        .build()
+        .expect("Failed to build test client");
+
+    // Act
+    let error = client.addresses().get("123").await.unwrap_err();
+
+    // Assert
+    assert!(matches!(error, TelnyxError::Connect(_)));
+    assert!(error.is_retryable());
+}
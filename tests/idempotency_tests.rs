@@ -0,0 +1,221 @@
+mod common;
+
+use std::time::Duration;
+
+use telnyx_rs::{RequestOptions, RetryPolicy, models::CreateAddressRequest};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{header, header_exists, method, path},
+};
+
+fn fast_retries() -> RetryPolicy {
+    RetryPolicy::builder()
+        .max_attempts(3)
+        .base_delay(Duration::from_millis(1))
+        .build()
+}
+
+fn create_request() -> CreateAddressRequest {
+    CreateAddressRequest::builder()
+        .street_address("311 W Superior St")
+        .locality("Chicago")
+        .country_code("US")
+        .build()
+}
+
+fn address_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": 123,
+            "record_type": "address",
+            "street_address": "311 W Superior St",
+            "locality": "Chicago",
+            "country_code": "US",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+}
+
+#[tokio::test]
+async fn post_with_idempotency_key_is_retried() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries())).await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header("Idempotency-Key", "order-1234"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header("Idempotency-Key", "order-1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().idempotency_key("order-1234"))
+        .create(create_request())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, 123);
+}
+
+#[tokio::test]
+async fn automatic_idempotency_keys_are_reused_across_retries() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .retry_policy(fast_retries())
+            .auto_idempotency_keys(true)
+    })
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header_exists("Idempotency-Key"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header_exists("Idempotency-Key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().create(create_request()).await;
+
+    // Assert
+    assert!(result.is_ok());
+    let requests = ctx.server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 2);
+    let first_key = requests[0].headers["idempotency-key"].to_str().unwrap();
+    let second_key = requests[1].headers["idempotency-key"].to_str().unwrap();
+    assert_eq!(first_key, second_key);
+    assert!(uuid_like(first_key));
+}
+
+#[tokio::test]
+async fn automatic_idempotency_keys_are_unique_per_call() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.auto_idempotency_keys(true)).await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    ctx.client
+        .addresses()
+        .create(create_request())
+        .await
+        .unwrap();
+    ctx.client
+        .addresses()
+        .create(create_request())
+        .await
+        .unwrap();
+
+    // Assert
+    let requests = ctx.server.received_requests().await.unwrap();
+    assert_ne!(
+        requests[0].headers["idempotency-key"],
+        requests[1].headers["idempotency-key"]
+    );
+}
+
+#[tokio::test]
+async fn idempotency_key_applies_to_one_post_on_a_handle() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.auto_idempotency_keys(true)).await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+
+    let addresses = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().idempotency_key("order-1234"));
+
+    // Act
+    addresses.create(create_request()).await.unwrap();
+    addresses.create(create_request()).await.unwrap();
+
+    // Assert
+    let requests = ctx.server.received_requests().await.unwrap();
+    assert_eq!(requests[0].headers["idempotency-key"], "order-1234");
+    let second_key = requests[1].headers["idempotency-key"].to_str().unwrap();
+    assert!(uuid_like(second_key));
+}
+
+#[tokio::test]
+async fn idempotency_key_is_not_reused_without_automatic_keys() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+
+    let addresses = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().idempotency_key("order-1234"));
+
+    // Act
+    addresses.create(create_request()).await.unwrap();
+    addresses.create(create_request()).await.unwrap();
+
+    // Assert
+    let requests = ctx.server.received_requests().await.unwrap();
+    assert_eq!(requests[0].headers["idempotency-key"], "order-1234");
+    assert!(!requests[1].headers.contains_key("idempotency-key"));
+}
+
+#[tokio::test]
+async fn no_idempotency_key_by_default() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    ctx.client
+        .addresses()
+        .create(create_request())
+        .await
+        .unwrap();
+
+    // Assert
+    let requests = ctx.server.received_requests().await.unwrap();
+    assert!(!requests[0].headers.contains_key("idempotency-key"));
+}
+
+fn uuid_like(value: &str) -> bool {
+    value.len() == 36 && value.chars().filter(|c| *c == '-').count() == 4
+}
@@ -0,0 +1,558 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        CreateManagedAccountRequest, EnableManagedAccountRequest, ListManagedAccountsParams,
+        ManagedAccount, ManagedAccountSort, UpdateManagedAccountRequest,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, body_json, method, path, query_param},
+};
+
+const ACCOUNT_ID: &str = "f65ceda4-6522-4ad6-aede-98de83385123";
+
+mod responses {
+    use super::ACCOUNT_ID;
+
+    pub fn managed_account_data() -> serde_json::Value {
+        serde_json::json!({
+            "id": ACCOUNT_ID,
+            "record_type": "managed_account",
+            "email": "admin@acme.example",
+            "manager_account_id": "f2ff9dd8-2c44-4e0c-9c3c-8b5f3b2a4e11",
+            "organization_name": "Acme Inc",
+            "api_key": "KEY01234",
+            "managed_account_allow_custom_pricing": false,
+            "rollup_billing": true,
+            "balance": {
+                "record_type": "balance",
+                "balance": "300.00",
+                "credit_limit": "100.00",
+                "available_credit": "400.00",
+                "currency": "USD"
+            },
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn managed_account_response() -> serde_json::Value {
+        serde_json::json!({ "data": managed_account_data() })
+    }
+
+    pub fn managed_account_list_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": [managed_account_data()],
+            "meta": { "total_pages": 1, "total_results": 1, "page_number": 1, "page_size": 20 }
+        })
+    }
+
+    pub fn allocatable_channels_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "record_type": "allocatable_global_outbound_channels",
+                "allocatable_global_outbound_channels": 500,
+                "total_global_channels_allocated": 120,
+                "managed_account_allow_custom_pricing": false
+            }
+        })
+    }
+}
+
+fn create_request() -> CreateManagedAccountRequest {
+    CreateManagedAccountRequest::builder()
+        .business_name("Acme Inc")
+        .email("admin@acme.example")
+        .rollup_billing(true)
+        .build()
+}
+
+fn update_request() -> UpdateManagedAccountRequest {
+    UpdateManagedAccountRequest::builder()
+        .managed_account_allow_custom_pricing(true)
+        .build()
+}
+
+fn managed_account() -> ManagedAccount {
+    serde_json::from_value(responses::managed_account_data()).unwrap()
+}
+
+#[tokio::test]
+async fn list_managed_accounts_with_params() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/managed_accounts"))
+        .and(query_param("filter[organization_name][contains]", "Acme"))
+        .and(query_param("include_cancelled_accounts", "true"))
+        .and(query_param("sort", "-created_at"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_list_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListManagedAccountsParams::builder()
+        .organization_name_contains("Acme")
+        .include_cancelled_accounts(true)
+        .sort(ManagedAccountSort::CreatedAtDesc)
+        .build();
+
+    // Act
+    let result = ctx.client.managed_accounts().list(Some(params)).await;
+
+    // Assert
+    let accounts = result.unwrap();
+    assert_eq!(accounts.data.len(), 1);
+    assert_eq!(accounts.data[0].id, ACCOUNT_ID);
+    assert_eq!(
+        accounts.data[0].balance.as_ref().unwrap().available_credit,
+        "400.00"
+    );
+}
+
+#[tokio::test]
+async fn list_managed_accounts_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/managed_accounts"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn create_managed_account_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/managed_accounts"))
+        .and(body_json(serde_json::json!({
+            "business_name": "Acme Inc",
+            "email": "admin@acme.example",
+            "rollup_billing": true
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().create(create_request()).await;
+
+    // Assert
+    let account = result.unwrap().data;
+    assert_eq!(account.organization_name.as_deref(), Some("Acme Inc"));
+    assert!(account.rollup_billing);
+}
+
+#[tokio::test]
+async fn create_managed_account_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/managed_accounts"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().create(create_request()).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_managed_account_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/managed_accounts/{ACCOUNT_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().get(ACCOUNT_ID).await;
+
+    // Assert
+    let account = result.unwrap().data;
+    assert_eq!(account.id, ACCOUNT_ID);
+    assert_eq!(account.email, "admin@acme.example");
+}
+
+#[tokio::test]
+async fn get_managed_account_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/managed_accounts/{ACCOUNT_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().get(ACCOUNT_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_managed_account_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/managed_accounts/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_managed_account_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/managed_accounts/{ACCOUNT_ID}")))
+        .and(body_json(serde_json::json!({
+            "managed_account_allow_custom_pricing": true
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .update(ACCOUNT_ID, update_request())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, ACCOUNT_ID);
+}
+
+#[tokio::test]
+async fn update_managed_account_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/managed_accounts/{ACCOUNT_ID}")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .update(ACCOUNT_ID, update_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn enable_managed_account_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/managed_accounts/{ACCOUNT_ID}/actions/enable"
+        )))
+        .and(body_json(
+            serde_json::json!({ "reenable_all_connections": true }),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = EnableManagedAccountRequest::builder()
+        .reenable_all_connections(true)
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .enable(ACCOUNT_ID, request)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn enable_managed_account_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/managed_accounts/nonexistent/actions/enable"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .enable("nonexistent", EnableManagedAccountRequest::default())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn disable_managed_account_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/managed_accounts/{ACCOUNT_ID}/actions/disable"
+        )))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::managed_account_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().disable(ACCOUNT_ID).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn disable_managed_account_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/managed_accounts/nonexistent/actions/disable"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.managed_accounts().disable("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn allocatable_global_outbound_channels_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/managed_accounts/allocatable_global_outbound_channels",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::allocatable_channels_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .allocatable_global_outbound_channels()
+        .await;
+
+    // Assert
+    let channels = result.unwrap().data;
+    assert_eq!(channels.allocatable_global_outbound_channels, 500);
+    assert_eq!(channels.total_global_channels_allocated, 120);
+}
+
+#[tokio::test]
+async fn allocatable_global_outbound_channels_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/managed_accounts/allocatable_global_outbound_channels",
+        ))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .managed_accounts()
+        .allocatable_global_outbound_channels()
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn scoped_client_authenticates_as_managed_account() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("KEY01234"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let sub_account = ctx.client.for_managed_account(&managed_account()).unwrap();
+
+    // Act
+    let result = sub_account.addresses().delete("123").await;
+
+    // Assert
+    assert_eq!(sub_account.managed_account_id(), Some(ACCOUNT_ID));
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn scoped_client_leaves_manager_client_unchanged() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/456"))
+        .and(bearer_token("test-api-key"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let _sub_account = ctx.client.for_managed_account(&managed_account()).unwrap();
+
+    // Act
+    let result = ctx.client.addresses().delete("456").await;
+
+    // Assert
+    assert_eq!(ctx.client.managed_account_id(), None);
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn scoping_to_managed_account_without_api_key_is_an_error() {
+    // Arrange
+    let ctx = common::setup().await;
+    let account = ManagedAccount {
+        id: ACCOUNT_ID.to_string(),
+        ..Default::default()
+    };
+
+    // Act
+    let result = ctx.client.for_managed_account(&account);
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
+
+#[test]
+fn debug_output_redacts_credentials() {
+    // Arrange
+    let mut account = managed_account();
+    account.api_user = Some("admin@acme.example".to_string());
+    account.api_token = Some("TOKEN5678".to_string());
+    let request = CreateManagedAccountRequest::builder()
+        .business_name("Acme Inc")
+        .password("hunter22")
+        .build();
+
+    // Act
+    let account = format!("{account:?}");
+    let request = format!("{request:?}");
+
+    // Assert
+    assert!(!account.contains("KEY01234"));
+    assert!(!account.contains("TOKEN5678"));
+    assert!(account.contains("[REDACTED]"));
+    assert!(!request.contains("hunter22"));
+    assert!(request.contains("Acme Inc"));
+}
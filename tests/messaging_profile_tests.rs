@@ -0,0 +1,597 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        CreateMessagingProfileRequest, ListMessagingProfilesParams, MessagingProfile,
+        MetricsTimeFrame, NumberPoolSettings, PageParams, UpdateMessagingProfileRequest,
+        WebhookApiVersion,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, body_json, method, path, query_param},
+};
+
+const PROFILE_ID: &str = "400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d";
+
+mod responses {
+    use super::PROFILE_ID;
+
+    pub fn profile_data(name: &str, enabled: bool) -> serde_json::Value {
+        serde_json::json!({
+            "id": PROFILE_ID,
+            "record_type": "messaging_profile",
+            "name": name,
+            "enabled": enabled,
+            "webhook_url": "https://example.com/webhooks/messaging",
+            "webhook_api_version": "2",
+            "whitelisted_destinations": ["US", "CA"],
+            "number_pool_settings": {
+                "toll_free_weight": 1.0,
+                "long_code_weight": 2.0,
+                "skip_unhealthy": true,
+                "sticky_sender": false,
+                "geomatch": false
+            },
+            "url_shortener_settings": null,
+            "daily_spend_limit": "100.00",
+            "daily_spend_limit_enabled": true,
+            "mms_fall_back_to_sms": false,
+            "mms_transcoding": true,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z"
+        })
+    }
+
+    pub fn profile_response(name: &str, enabled: bool) -> serde_json::Value {
+        serde_json::json!({ "data": profile_data(name, enabled) })
+    }
+
+    pub fn list_response(data: Vec<serde_json::Value>) -> serde_json::Value {
+        serde_json::json!({
+            "data": data,
+            "meta": {
+                "page_number": 1,
+                "page_size": 20,
+                "total_pages": 1,
+                "total_results": data.len()
+            }
+        })
+    }
+
+    pub fn phone_number_data() -> serde_json::Value {
+        serde_json::json!({
+            "id": "1293384261075731499",
+            "record_type": "messaging_phone_number",
+            "phone_number": "+13125550000",
+            "messaging_profile_id": PROFILE_ID,
+            "country_code": "US",
+            "type": "long-code",
+            "traffic_type": "A2P",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn short_code_data() -> serde_json::Value {
+        serde_json::json!({
+            "id": "12ade33a-21c0-473b-b055-b3c836e1c292",
+            "record_type": "short_code",
+            "short_code": "12345",
+            "country_code": "US",
+            "messaging_profile_id": PROFILE_ID,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn metrics_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "overview": {
+                    "record_type": "messaging_profile_metrics",
+                    "phone_numbers": 3,
+                    "outbound": { "sent": 120, "delivered": 118, "error_ratio": 0.0167 },
+                    "inbound": { "received": 42 }
+                },
+                "detailed": [{ "metric": "sent", "values": [] }]
+            }
+        })
+    }
+}
+
+fn create_request() -> CreateMessagingProfileRequest {
+    CreateMessagingProfileRequest::builder()
+        .name("Notifications")
+        .whitelisted_destinations(vec!["US".into(), "CA".into()])
+        .number_pool_settings(
+            NumberPoolSettings::builder()
+                .toll_free_weight(1.0)
+                .long_code_weight(2.0)
+                .sticky_sender(true)
+                .build(),
+        )
+        .build()
+}
+
+#[tokio::test]
+async fn list_messaging_profiles_with_filter() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles"))
+        .and(bearer_token("test-api-key"))
+        .and(query_param("filter[name]", "Notifications"))
+        .and(query_param("page[size]", "20"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::list_response(vec![
+                responses::profile_data("Notifications", true),
+            ])),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListMessagingProfilesParams::builder()
+        .name("Notifications")
+        .page_size(20)
+        .build();
+
+    // Act
+    let result = ctx.client.messaging_profiles().list(Some(params)).await;
+
+    // Assert
+    let profiles = result.unwrap().data;
+    assert_eq!(profiles.len(), 1);
+    assert_eq!(profiles[0].name, "Notifications");
+    assert_eq!(profiles[0].webhook_api_version, Some(WebhookApiVersion::V2));
+    let pool = profiles[0].number_pool_settings.as_ref().unwrap();
+    assert_eq!(pool.long_code_weight, 2.0);
+    assert!(profiles[0].url_shortener_settings.is_none());
+}
+
+#[tokio::test]
+async fn list_messaging_profiles_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_messaging_profile_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::profile_response("Notifications", true)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().get(PROFILE_ID).await;
+
+    // Assert
+    let profile = result.unwrap().data;
+    assert_eq!(profile.id, PROFILE_ID);
+    assert_eq!(profile.daily_spend_limit.as_deref(), Some("100.00"));
+}
+
+#[tokio::test]
+async fn get_messaging_profile_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().get(PROFILE_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_messaging_profile_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn create_messaging_profile_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messaging_profiles"))
+        .and(body_json(serde_json::json!({
+            "name": "Notifications",
+            "whitelisted_destinations": ["US", "CA"],
+            "number_pool_settings": {
+                "toll_free_weight": 1.0,
+                "long_code_weight": 2.0,
+                "skip_unhealthy": false,
+                "sticky_sender": true,
+                "geomatch": false
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::profile_response("Notifications", true)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .create(create_request())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, PROFILE_ID);
+}
+
+#[tokio::test]
+async fn create_messaging_profile_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messaging_profiles"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .create(create_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_messaging_profile_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}")))
+        .and(body_json(serde_json::json!({ "enabled": false })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::profile_response("Notifications", false)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = UpdateMessagingProfileRequest::builder()
+        .enabled(false)
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .update(PROFILE_ID, request)
+        .await;
+
+    // Assert
+    assert!(!result.unwrap().data.enabled);
+}
+
+#[tokio::test]
+async fn update_messaging_profile_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = UpdateMessagingProfileRequest::builder()
+        .daily_spend_limit("-1")
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .update(PROFILE_ID, request)
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn delete_messaging_profile_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::profile_response("Notifications", false)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().delete(PROFILE_ID).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn delete_messaging_profile_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/messaging_profiles/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messaging_profiles().delete("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn list_messaging_profile_phone_numbers_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/messaging_profiles/{PROFILE_ID}/phone_numbers"
+        )))
+        .and(query_param("page[number]", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::list_response(vec![
+                responses::phone_number_data(),
+            ])),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .list_phone_numbers(
+            PROFILE_ID,
+            Some(PageParams::builder().page_number(2).build()),
+        )
+        .await;
+
+    // Assert
+    let numbers = result.unwrap().data;
+    assert_eq!(numbers[0].phone_number, "+13125550000");
+    assert_eq!(numbers[0].number_type.as_deref(), Some("long-code"));
+}
+
+#[tokio::test]
+async fn list_messaging_profile_phone_numbers_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles/nonexistent/phone_numbers"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .list_phone_numbers("nonexistent", None)
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn list_messaging_profile_short_codes_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/messaging_profiles/{PROFILE_ID}/short_codes"
+        )))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::list_response(vec![responses::short_code_data()])),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .list_short_codes(PROFILE_ID, None)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data[0].short_code, "12345");
+}
+
+#[tokio::test]
+async fn list_messaging_profile_short_codes_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles/nonexistent/short_codes"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .list_short_codes("nonexistent", None)
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_messaging_profile_metrics_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messaging_profiles/{PROFILE_ID}/metrics")))
+        .and(query_param("time_frame", "7d"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(responses::metrics_response()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .metrics(PROFILE_ID, Some(MetricsTimeFrame::SevenDays))
+        .await;
+
+    // Assert
+    let metrics = result.unwrap().data;
+    let overview = metrics.overview.unwrap();
+    assert_eq!(overview.phone_numbers, Some(3));
+    assert_eq!(overview.outbound.unwrap().delivered, 118);
+    assert_eq!(overview.inbound.unwrap().received, 42);
+    assert_eq!(metrics.detailed.len(), 1);
+}
+
+#[tokio::test]
+async fn get_messaging_profile_metrics_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messaging_profiles/nonexistent/metrics"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .messaging_profiles()
+        .metrics("nonexistent", None)
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[test]
+fn debug_output_redacts_v1_secret() {
+    // Arrange
+    let mut profile: MessagingProfile =
+        serde_json::from_value(responses::profile_data("Customer notifications", true)).unwrap();
+    profile.v1_secret = Some("rP1VamejkU2v0qIUxntqLW2c".to_string());
+
+    // Act
+    let debug = format!("{profile:?}");
+
+    // Assert
+    assert!(!debug.contains("rP1VamejkU2v0qIUxntqLW2c"));
+    assert!(debug.contains("[REDACTED]"));
+    assert!(debug.contains("Customer notifications"));
+}
@@ -0,0 +1,460 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        MessageDirection, MessageStatus, MessageType, SendGroupMmsRequest, SendMessageRequest,
+        SendNumberPoolMessageRequest, SendShortCodeMessageRequest,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, body_json, method, path},
+};
+
+const MESSAGE_ID: &str = "40385f64-5717-4562-b3fc-2c963f66afa6";
+const PROFILE_ID: &str = "400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d";
+
+mod responses {
+    use super::{MESSAGE_ID, PROFILE_ID};
+
+    pub fn message_response(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": MESSAGE_ID,
+                "record_type": "message",
+                "direction": "outbound",
+                "type": "SMS",
+                "messaging_profile_id": PROFILE_ID,
+                "from": { "phone_number": "+13125550000", "carrier": "Telnyx", "line_type": "Wireless" },
+                "to": [{ "phone_number": "+13125550001", "status": status, "carrier": "T-Mobile" }],
+                "text": "Hello",
+                "encoding": "GSM-7",
+                "parts": 1,
+                "cost": { "amount": "0.0051", "currency": "USD" },
+                "received_at": "2024-01-01T00:00:00Z",
+                "errors": []
+            }
+        })
+    }
+
+    pub fn invalid_recipient_response() -> serde_json::Value {
+        serde_json::json!({
+            "errors": [{
+                "code": "40310",
+                "title": "Invalid 'to' address",
+                "detail": "The 'to' address should be in +E.164 format.",
+                "source": { "pointer": "/to" }
+            }]
+        })
+    }
+}
+
+fn sms_request() -> SendMessageRequest {
+    SendMessageRequest::builder()
+        .from("+13125550000")
+        .to("+13125550001")
+        .text("Hello")
+        .build()
+}
+
+#[tokio::test]
+async fn send_message_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(bearer_token("test-api-key"))
+        .and(body_json(serde_json::json!({
+            "from": "+13125550000",
+            "to": "+13125550001",
+            "text": "Hello",
+            "webhook_url": "https://example.com/webhooks",
+            "use_profile_webhooks": false,
+            "type": "SMS"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("queued")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendMessageRequest::builder()
+        .from("+13125550000")
+        .to("+13125550001")
+        .text("Hello")
+        .webhook_url("https://example.com/webhooks")
+        .use_profile_webhooks(false)
+        .message_type(MessageType::Sms)
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send(request).await;
+
+    // Assert
+    let message = result.unwrap().data;
+    assert_eq!(message.id, MESSAGE_ID);
+    assert_eq!(message.direction, MessageDirection::Outbound);
+    assert_eq!(message.message_type, MessageType::Sms);
+    assert_eq!(message.to[0].status, MessageStatus::Queued);
+    assert_eq!(message.cost.unwrap().amount, "0.0051");
+}
+
+#[tokio::test]
+async fn send_mms_with_media_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(body_json(serde_json::json!({
+            "from": "+13125550000",
+            "to": "+13125550001",
+            "media_urls": ["https://example.com/image.png"],
+            "messaging_profile_id": PROFILE_ID,
+            "type": "MMS"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("queued")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendMessageRequest::builder()
+        .from("+13125550000")
+        .to("+13125550001")
+        .media_urls(vec!["https://example.com/image.png".into()])
+        .messaging_profile_id(PROFILE_ID)
+        .message_type(MessageType::Mms)
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send(request).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn send_message_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().send(sms_request()).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn send_message_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(
+            ResponseTemplate::new(422).set_body_json(responses::invalid_recipient_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().send(sms_request()).await;
+
+    // Assert
+    match result.unwrap_err() {
+        TelnyxError::Api { status, errors, .. } => {
+            assert_eq!(status, 422);
+            assert_eq!(errors[0].code, "40310");
+        }
+        other => panic!("unexpected error: {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn send_with_number_pool_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/number_pool"))
+        .and(body_json(serde_json::json!({
+            "messaging_profile_id": PROFILE_ID,
+            "to": "+13125550001",
+            "text": "Hello"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("queued")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendNumberPoolMessageRequest::builder()
+        .messaging_profile_id(PROFILE_ID)
+        .to("+13125550001")
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_with_number_pool(request).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, MESSAGE_ID);
+}
+
+#[tokio::test]
+async fn send_with_number_pool_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/number_pool"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendNumberPoolMessageRequest::builder()
+        .messaging_profile_id(PROFILE_ID)
+        .to("not-a-number")
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_with_number_pool(request).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn send_short_code_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/short_code"))
+        .and(body_json(serde_json::json!({
+            "from": "12345",
+            "to": "+13125550001",
+            "text": "Hello"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("queued")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendShortCodeMessageRequest::builder()
+        .from("12345")
+        .to("+13125550001")
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_short_code(request).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, MESSAGE_ID);
+}
+
+#[tokio::test]
+async fn send_short_code_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/short_code"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendShortCodeMessageRequest::builder()
+        .from("123")
+        .to("+13125550001")
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_short_code(request).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn send_group_mms_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/group_mms"))
+        .and(body_json(serde_json::json!({
+            "from": "+13125550000",
+            "to": ["+13125550001", "+13125550002"],
+            "text": "Hello"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("queued")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendGroupMmsRequest::builder()
+        .from("+13125550000")
+        .to(vec!["+13125550001".into(), "+13125550002".into()])
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_group_mms(request).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, MESSAGE_ID);
+}
+
+#[tokio::test]
+async fn send_group_mms_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages/group_mms"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = SendGroupMmsRequest::builder()
+        .from("+13125550000")
+        .to(vec!["+13125550001".into()])
+        .text("Hello")
+        .build();
+
+    // Act
+    let result = ctx.client.messages().send_group_mms(request).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_message_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messages/{MESSAGE_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::message_response("delivered")),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().get(MESSAGE_ID).await;
+
+    // Assert
+    let status = result.unwrap().data.to[0].status;
+    assert_eq!(status, MessageStatus::Delivered);
+    assert!(status.is_final());
+}
+
+#[tokio::test]
+async fn get_message_unknown_enum_values_fall_back() {
+    // Arrange
+    let ctx = common::setup().await;
+    let mut body = responses::message_response("carrier_rejected");
+    body["data"]["direction"] = "sideways".into();
+    body["data"]["type"] = "RCS".into();
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messages/{MESSAGE_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().get(MESSAGE_ID).await;
+
+    // Assert
+    let message = result.unwrap().data;
+    assert_eq!(message.direction, MessageDirection::Unknown);
+    assert_eq!(message.message_type, MessageType::Unknown);
+    assert_eq!(message.to[0].status, MessageStatus::Unknown);
+}
+
+#[tokio::test]
+async fn get_message_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/messages/{MESSAGE_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().get(MESSAGE_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_message_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/messages/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.messages().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
@@ -0,0 +1,160 @@
+mod common;
+
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use telnyx_rs::{
+    Middleware, Next, StatusCode, TelnyxError, TransportRequest, TransportResponse,
+    header::HeaderValue,
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{header, method, path},
+};
+
+struct StaticHeader(&'static str, &'static str);
+
+impl Middleware for StaticHeader {
+    fn handle<'a>(
+        &'a self,
+        mut request: TransportRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>> {
+        request
+            .headers
+            .insert(self.0, HeaderValue::from_static(self.1));
+        next.run(request)
+    }
+}
+
+/// Records the order middleware ran in and the statuses they observed
+struct Recorder {
+    name: &'static str,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Recorder {
+    fn handle<'a>(
+        &'a self,
+        request: TransportRequest,
+        next: Next<'a>,
+    ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>> {
+        Box::pin(async move {
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} > {}", self.name, request.method));
+            let response = next.run(request).await?;
+            self.log
+                .lock()
+                .unwrap()
+                .push(format!("{} < {}", self.name, response.status.as_u16()));
+            Ok(response)
+        })
+    }
+}
+
+struct ShortCircuit;
+
+impl Middleware for ShortCircuit {
+    fn handle<'a>(
+        &'a self,
+        _request: TransportRequest,
+        _next: Next<'a>,
+    ) -> BoxFuture<'a, Result<TransportResponse, TelnyxError>> {
+        Box::pin(async {
+            Ok(TransportResponse {
+                status: StatusCode::SERVICE_UNAVAILABLE,
+                ..Default::default()
+            })
+        })
+    }
+}
+
+#[tokio::test]
+async fn middleware_adds_headers() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .middleware(StaticHeader("x-tenant-id", "tenant-42"))
+            .middleware(StaticHeader("x-audit", "on"))
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .and(header("x-tenant-id", "tenant-42"))
+        .and(header("x-audit", "on"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn middleware_runs_in_registration_order() {
+    // Arrange
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let ctx = common::setup_with(|builder| {
+        builder
+            .middleware(Recorder {
+                name: "outer",
+                log: log.clone(),
+            })
+            .middleware(Recorder {
+                name: "inner",
+                log: log.clone(),
+            })
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(404))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(result.is_err());
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![
+            "outer > DELETE",
+            "inner > DELETE",
+            "inner < 404",
+            "outer < 404"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn middleware_can_short_circuit() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .retry_policy(telnyx_rs::RetryPolicy::disabled())
+            .middleware(ShortCircuit)
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert_eq!(result.unwrap_err().status(), Some(503));
+}
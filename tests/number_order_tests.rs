@@ -0,0 +1,406 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        CreateNumberOrderRequest, ListNumberOrdersParams, NumberOrderStatus,
+        PhoneNumberOrderRequest, PhoneNumberType, RegulatoryRequirement, UpdateNumberOrderRequest,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
+
+const ORDER_ID: &str = "12ade33a-21c0-473b-b055-b3c836e1c292";
+const SUB_ORDER_ID: &str = "3b4c2d1e-0f9a-4b8c-9d7e-6f5a4b3c2d1e";
+const REQUIREMENT_ID: &str = "8ffb3622-7c6b-4ccc-b65f-7a3dc0099576";
+
+mod responses {
+    use super::{ORDER_ID, REQUIREMENT_ID, SUB_ORDER_ID};
+
+    pub fn number_order_data(status: &str, requirements_met: bool) -> serde_json::Value {
+        serde_json::json!({
+            "id": ORDER_ID,
+            "record_type": "number_order",
+            "phone_numbers": [{
+                "id": "dc8e4d67-33a0-4629-9b6d-e5a86e8e8fd5",
+                "record_type": "number_order_phone_number",
+                "phone_number": "+13125550000",
+                "status": status,
+                "requirements_met": requirements_met,
+                "regulatory_requirements": [{
+                    "requirement_id": REQUIREMENT_ID,
+                    "field_type": "address",
+                    "field_value": "45f45a04-b4be-4592-95b1-9306b9db2b21"
+                }]
+            }],
+            "phone_numbers_count": 1,
+            "status": status,
+            "connection_id": "1494404757140276705",
+            "customer_reference": "customer-42",
+            "sub_number_orders_ids": [SUB_ORDER_ID],
+            "requirements_met": requirements_met,
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn number_order_response(status: &str, requirements_met: bool) -> serde_json::Value {
+        serde_json::json!({ "data": number_order_data(status, requirements_met) })
+    }
+
+    pub fn number_order_list_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": [number_order_data("success", true)],
+            "meta": {
+                "page_number": 1,
+                "page_size": 20,
+                "total_pages": 1,
+                "total_results": 1
+            }
+        })
+    }
+
+    pub fn sub_number_order_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": SUB_ORDER_ID,
+                "record_type": "sub_number_order",
+                "order_request_id": ORDER_ID,
+                "country_code": "US",
+                "phone_number_type": "local",
+                "phone_numbers_count": 1,
+                "status": "pending",
+                "requirements_met": false,
+                "regulatory_requirements": [{
+                    "record_type": "phone_number_regulatory_requirement",
+                    "requirement_id": REQUIREMENT_ID,
+                    "field_type": "address"
+                }],
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-01T00:00:00Z"
+            }
+        })
+    }
+}
+
+fn create_request() -> CreateNumberOrderRequest {
+    CreateNumberOrderRequest::builder()
+        .phone_numbers(vec![
+            PhoneNumberOrderRequest::builder()
+                .phone_number("+13125550000")
+                .build(),
+            PhoneNumberOrderRequest::builder()
+                .phone_number("+13125550001")
+                .requirement_group_id("a4b201f9-8646-4e54-a7d2-b2e403eeaf8c")
+                .build(),
+        ])
+        .connection_id("1494404757140276705")
+        .customer_reference("customer-42")
+        .build()
+}
+
+fn update_request() -> UpdateNumberOrderRequest {
+    UpdateNumberOrderRequest::builder()
+        .regulatory_requirements(vec![
+            RegulatoryRequirement::builder()
+                .requirement_id(REQUIREMENT_ID)
+                .field_value("45f45a04-b4be-4592-95b1-9306b9db2b21")
+                .build(),
+        ])
+        .build()
+}
+
+#[tokio::test]
+async fn create_number_order_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/number_orders"))
+        .and(body_json(serde_json::json!({
+            "phone_numbers": [
+                { "phone_number": "+13125550000" },
+                { "phone_number": "+13125550001", "requirement_group_id": "a4b201f9-8646-4e54-a7d2-b2e403eeaf8c" }
+            ],
+            "connection_id": "1494404757140276705",
+            "customer_reference": "customer-42"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::number_order_response("pending", false)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().create(create_request()).await;
+
+    // Assert
+    let order = result.unwrap().data;
+    assert_eq!(order.id, ORDER_ID);
+    assert_eq!(order.status, NumberOrderStatus::Pending);
+    assert!(!order.status.is_final());
+    assert!(!order.requirements_met);
+    let requirement = &order.phone_numbers[0].regulatory_requirements[0];
+    assert_eq!(requirement.requirement_id, REQUIREMENT_ID);
+    assert_eq!(requirement.field_type.as_deref(), Some("address"));
+}
+
+#[tokio::test]
+async fn create_number_order_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/number_orders"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().create(create_request()).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn list_number_orders_with_filters() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_orders"))
+        .and(query_param("filter[status]", "success"))
+        .and(query_param(
+            "filter[created_at][gt]",
+            "2024-01-01T00:00:00Z",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::number_order_list_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListNumberOrdersParams::builder()
+        .status(NumberOrderStatus::Success)
+        .created_at_gt("2024-01-01T00:00:00Z".parse().unwrap())
+        .build();
+
+    // Act
+    let result = ctx.client.number_orders().list(Some(params)).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data[0].status, NumberOrderStatus::Success);
+}
+
+#[tokio::test]
+async fn list_number_orders_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_orders"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_number_order_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/number_orders/{ORDER_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::number_order_response("failure", false)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().get(ORDER_ID).await;
+
+    // Assert
+    assert!(result.unwrap().data.status.is_final());
+}
+
+#[tokio::test]
+async fn get_number_order_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/number_orders/{ORDER_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().get(ORDER_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_number_order_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_orders/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_orders().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_number_order_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/number_orders/{ORDER_ID}")))
+        .and(body_json(serde_json::json!({
+            "regulatory_requirements": [{
+                "requirement_id": REQUIREMENT_ID,
+                "field_value": "45f45a04-b4be-4592-95b1-9306b9db2b21"
+            }]
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::number_order_response("pending", true)),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_orders()
+        .update(ORDER_ID, update_request())
+        .await;
+
+    // Assert
+    assert!(result.unwrap().data.requirements_met);
+}
+
+#[tokio::test]
+async fn update_number_order_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/number_orders/{ORDER_ID}")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_orders()
+        .update(ORDER_ID, update_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_sub_number_order_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/sub_number_orders/{SUB_ORDER_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::sub_number_order_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_orders()
+        .get_sub_number_order(SUB_ORDER_ID)
+        .await;
+
+    // Assert
+    let sub_order = result.unwrap().data;
+    assert_eq!(sub_order.order_request_id, ORDER_ID);
+    assert_eq!(sub_order.phone_number_type, PhoneNumberType::Local);
+    assert_eq!(
+        sub_order.regulatory_requirements[0].requirement_id,
+        REQUIREMENT_ID
+    );
+}
+
+#[tokio::test]
+async fn get_sub_number_order_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sub_number_orders/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_orders()
+        .get_sub_number_order("nonexistent")
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
@@ -0,0 +1,318 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        CreateNumberReservationRequest, ListNumberReservationsParams,
+        PhoneNumberReservationRequest, ReservationStatus, ReservedPhoneNumberStatus,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
+
+const RESERVATION_ID: &str = "12ade33a-21c0-473b-b055-b3c836e1c292";
+
+mod responses {
+    use super::RESERVATION_ID;
+
+    pub fn reservation_data(status: &str, numbers: &[(&str, &str)]) -> serde_json::Value {
+        let phone_numbers: Vec<serde_json::Value> = numbers
+            .iter()
+            .map(|(phone_number, number_status)| {
+                serde_json::json!({
+                    "id": "dc8e4d67-33a0-4629-9b6d-e5a86e8e8fd5",
+                    "record_type": "reserved_phone_number",
+                    "phone_number": phone_number,
+                    "status": number_status,
+                    "expired_at": "2024-01-01T00:30:00Z",
+                    "created_at": "2024-01-01T00:00:00Z",
+                    "updated_at": "2024-01-01T00:00:00Z"
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "id": RESERVATION_ID,
+            "record_type": "number_reservation",
+            "phone_numbers": phone_numbers,
+            "status": status,
+            "customer_reference": "customer-42",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        })
+    }
+
+    pub fn reservation_response(status: &str, numbers: &[(&str, &str)]) -> serde_json::Value {
+        serde_json::json!({ "data": reservation_data(status, numbers) })
+    }
+
+    pub fn reservation_list_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": [reservation_data("success", &[("+13125550000", "success")])],
+            "meta": {
+                "page_number": 1,
+                "page_size": 20,
+                "total_pages": 1,
+                "total_results": 1
+            }
+        })
+    }
+}
+
+fn create_request() -> CreateNumberReservationRequest {
+    CreateNumberReservationRequest::builder()
+        .phone_numbers(vec![
+            PhoneNumberReservationRequest::builder()
+                .phone_number("+13125550000")
+                .build(),
+        ])
+        .customer_reference("customer-42")
+        .build()
+}
+
+#[tokio::test]
+async fn create_reservation_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/number_reservations"))
+        .and(body_json(serde_json::json!({
+            "phone_numbers": [{ "phone_number": "+13125550000" }],
+            "customer_reference": "customer-42"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::reservation_response(
+                "pending",
+                &[("+13125550000", "pending")],
+            )),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_reservations()
+        .create(create_request())
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.status, ReservationStatus::Pending);
+}
+
+#[tokio::test]
+async fn create_reservation_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/number_reservations"))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_reservations()
+        .create(create_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_reservation_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/number_reservations/{RESERVATION_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::reservation_response(
+                "success",
+                &[("+13125550000", "expired")],
+            )),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_reservations().get(RESERVATION_ID).await;
+
+    // Assert
+    let reservation = result.unwrap().data;
+    assert_eq!(reservation.status, ReservationStatus::Success);
+    assert_eq!(
+        reservation.phone_numbers[0].status,
+        ReservedPhoneNumberStatus::Expired
+    );
+}
+
+#[tokio::test]
+async fn get_reservation_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/number_reservations/{RESERVATION_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_reservations().get(RESERVATION_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_reservation_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_reservations/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_reservations().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn extend_reservation_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/number_reservations/{RESERVATION_ID}/actions/extend"
+        )))
+        .and(body_json(serde_json::json!({})))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::reservation_response(
+                "success",
+                &[("+13125550000", "success")],
+            )),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .number_reservations()
+        .extend(RESERVATION_ID)
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap().data.phone_numbers[0].status,
+        ReservedPhoneNumberStatus::Success
+    );
+}
+
+#[tokio::test]
+async fn extend_reservation_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/number_reservations/nonexistent/actions/extend"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_reservations().extend("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn list_reservations_with_filters() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_reservations"))
+        .and(query_param("filter[status]", "success"))
+        .and(query_param(
+            "filter[phone_numbers.phone_number]",
+            "+13125550000",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::reservation_list_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListNumberReservationsParams::builder()
+        .status(ReservationStatus::Success)
+        .phone_number("+13125550000")
+        .build();
+
+    // Act
+    let result = ctx.client.number_reservations().list(Some(params)).await;
+
+    // Assert
+    let reservations = result.unwrap().data;
+    assert_eq!(reservations.len(), 1);
+    assert_eq!(
+        reservations[0].customer_reference.as_deref(),
+        Some("customer-42")
+    );
+}
+
+#[tokio::test]
+async fn list_reservations_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/number_reservations"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.number_reservations().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
@@ -0,0 +1,197 @@
+mod common;
+
+use futures::StreamExt;
+use telnyx_rs::{TelnyxError, models::ListAddressesParams};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+fn address(id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "record_type": "address",
+        "street_address": "311 W Superior St",
+        "locality": "Chicago",
+        "country_code": "US",
+        "created_at": "2024-01-01T00:00:00Z",
+        "updated_at": "2024-01-01T00:00:00Z"
+    })
+}
+
+fn page(ids: &[i64], page_number: i32, total_pages: i32) -> serde_json::Value {
+    serde_json::json!({
+        "data": ids.iter().map(|id| address(*id)).collect::<Vec<_>>(),
+        "meta": {
+            "total_pages": total_pages,
+            "total_results": total_pages * 2,
+            "page_number": page_number,
+            "page_size": 2
+        }
+    })
+}
+
+async fn mount_page(ctx: &common::TestContext, page_number: i32, body: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .and(query_param("page[number]", page_number.to_string()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .mount(&ctx.server)
+        .await;
+}
+
+#[tokio::test]
+async fn collect_all_fetches_every_page() {
+    // Arrange
+    let ctx = common::setup().await;
+    mount_page(&ctx, 1, page(&[1, 2], 1, 3)).await;
+    mount_page(&ctx, 2, page(&[3, 4], 2, 3)).await;
+    mount_page(&ctx, 3, page(&[5], 3, 3)).await;
+
+    // Act
+    let result = ctx.client.addresses().list_all(None).collect_all().await;
+
+    // Assert
+    let ids: Vec<i64> = result.unwrap().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn stream_forwards_filters_to_every_page() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    for page_number in 1..=2 {
+        Mock::given(method("GET"))
+            .and(path("/addresses"))
+            .and(query_param("page[number]", page_number.to_string()))
+            .and(query_param("page[size]", "2"))
+            .and(query_param("filter[address_book][eq]", "true"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(page(
+                &[page_number.into()],
+                page_number,
+                2,
+            )))
+            .expect(1)
+            .mount(&ctx.server)
+            .await;
+    }
+
+    let params = ListAddressesParams::builder()
+        .page_size(2)
+        .address_book_eq(true)
+        .build();
+
+    // Act
+    let mut stream = ctx.client.addresses().list_all(Some(params));
+    let mut ids = Vec::new();
+    while let Some(address) = stream.next().await {
+        ids.push(address.unwrap().id);
+    }
+
+    // Assert
+    assert_eq!(ids, vec![1, 2]);
+}
+
+#[tokio::test]
+async fn max_items_stops_fetching_pages() {
+    // Arrange
+    let ctx = common::setup().await;
+    mount_page(&ctx, 1, page(&[1, 2], 1, 3)).await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .and(query_param("page[number]", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&[3, 4], 2, 3)))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list_all(None)
+        .max_items(3)
+        .collect_all()
+        .await;
+
+    // Assert
+    let ids: Vec<i64> = result.unwrap().iter().map(|a| a.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn max_pages_caps_fetched_pages() {
+    // Arrange
+    let ctx = common::setup().await;
+    mount_page(&ctx, 1, page(&[1, 2], 1, 100)).await;
+    mount_page(&ctx, 2, page(&[3, 4], 2, 100)).await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .and(query_param("page[number]", "3"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&[5, 6], 3, 100)))
+        .expect(0)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list_all(None)
+        .max_pages(2)
+        .collect_all()
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn max_pages_zero_fetches_nothing() {
+    // Arrange
+    let ctx = common::setup().await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page(&[1, 2], 1, 1)))
+        .expect(0)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list_all(None)
+        .max_pages(0)
+        .collect_all()
+        .await;
+
+    // Assert
+    assert!(result.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn error_ends_the_stream() {
+    // Arrange
+    let ctx = common::setup().await;
+    mount_page(&ctx, 1, page(&[1, 2], 1, 2)).await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .and(query_param("page[number]", "2"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let items: Vec<_> = ctx.client.addresses().list_all(None).collect().await;
+
+    // Assert
+    assert_eq!(items.len(), 3);
+    assert!(items[0].is_ok());
+    assert!(items[1].is_ok());
+    assert!(matches!(
+        items[2],
+        Err(TelnyxError::Api { status: 401, .. })
+    ));
+}
@@ -0,0 +1,586 @@
+mod common;
+
+use telnyx_rs::{
+    TelnyxError,
+    models::{
+        CallForwarding, ListPhoneNumbersParams, PhoneNumber, PhoneNumberSort, PhoneNumberStatus,
+        UpdatePhoneNumberMessagingRequest, UpdatePhoneNumberRequest,
+        UpdatePhoneNumberVoiceSettingsRequest, UsagePaymentMethod,
+    },
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{body_json, method, path, query_param},
+};
+
+const NUMBER_ID: &str = "1293384261075731499";
+const PROFILE_ID: &str = "400174e4-1b5c-4b1e-8e2f-3d9a1b1b3c5d";
+
+mod responses {
+    use super::{NUMBER_ID, PROFILE_ID};
+
+    pub fn phone_number_data(status: &str, tags: &[&str]) -> serde_json::Value {
+        serde_json::json!({
+            "id": NUMBER_ID,
+            "record_type": "phone_number",
+            "phone_number": "+13125550000",
+            "status": status,
+            "tags": tags,
+            "connection_id": "1494404757140276705",
+            "connection_name": "Main SIP trunk",
+            "customer_reference": "MY REF 001",
+            "billing_group_id": "62e4bf2e-c278-4282-b524-488d9c9c43b2",
+            "emergency_enabled": true,
+            "emergency_address_id": "1315261609962112019",
+            "call_forwarding_enabled": true,
+            "phone_number_type": "local",
+            "purchased_at": "2024-01-01T00:00:00Z",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-02T00:00:00Z"
+        })
+    }
+
+    pub fn phone_number_response(status: &str, tags: &[&str]) -> serde_json::Value {
+        serde_json::json!({ "data": phone_number_data(status, tags) })
+    }
+
+    pub fn phone_number_list_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": [phone_number_data("active", &["support"])],
+            "meta": {
+                "page_number": 1,
+                "page_size": 20,
+                "total_pages": 1,
+                "total_results": 1
+            }
+        })
+    }
+
+    pub fn voice_settings_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": NUMBER_ID,
+                "record_type": "voice_settings",
+                "connection_id": "1494404757140276705",
+                "tech_prefix_enabled": false,
+                "usage_payment_method": "pay-per-minute",
+                "call_forwarding": {
+                    "call_forwarding_enabled": true,
+                    "forwards_to": "+13125550002",
+                    "forwarding_type": "on-failure"
+                },
+                "emergency": {
+                    "emergency_enabled": true,
+                    "emergency_address_id": "1315261609962112019",
+                    "emergency_status": "active"
+                }
+            }
+        })
+    }
+
+    pub fn messaging_settings_response() -> serde_json::Value {
+        serde_json::json!({
+            "data": {
+                "id": NUMBER_ID,
+                "record_type": "messaging_settings",
+                "phone_number": "+13125550000",
+                "messaging_profile_id": PROFILE_ID,
+                "messaging_product": "P2P",
+                "created_at": "2024-01-01T00:00:00Z",
+                "updated_at": "2024-01-02T00:00:00Z"
+            }
+        })
+    }
+}
+
+fn voice_settings_request() -> UpdatePhoneNumberVoiceSettingsRequest {
+    UpdatePhoneNumberVoiceSettingsRequest::builder()
+        .call_forwarding(
+            CallForwarding::builder()
+                .call_forwarding_enabled(true)
+                .forwards_to("+13125550002")
+                .forwarding_type("on-failure")
+                .build(),
+        )
+        .build()
+}
+
+fn messaging_settings_request() -> UpdatePhoneNumberMessagingRequest {
+    UpdatePhoneNumberMessagingRequest::builder()
+        .messaging_profile_id(PROFILE_ID)
+        .build()
+}
+
+#[tokio::test]
+async fn list_phone_numbers_with_filters() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers"))
+        .and(query_param("filter[tag]", "support"))
+        .and(query_param("filter[status]", "active"))
+        .and(query_param("filter[connection_id]", "1494404757140276705"))
+        .and(query_param("filter[emergency_enabled]", "true"))
+        .and(query_param("sort", "-purchased_at"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::phone_number_list_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let params = ListPhoneNumbersParams::builder()
+        .tag("support")
+        .status(PhoneNumberStatus::Active)
+        .connection_id("1494404757140276705")
+        .emergency_enabled(true)
+        .sort(PhoneNumberSort::PurchasedAtDesc)
+        .build();
+
+    // Act
+    let result = ctx.client.phone_numbers().list(Some(params)).await;
+
+    // Assert
+    let response = result.unwrap();
+    assert_eq!(response.meta.unwrap().total_results, 1);
+    let number = &response.data[0];
+    assert_eq!(number.phone_number, "+13125550000");
+    assert_eq!(number.status, PhoneNumberStatus::Active);
+    assert_eq!(number.tags, vec!["support"]);
+    assert!(number.emergency_enabled);
+}
+
+#[tokio::test]
+async fn list_phone_numbers_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers"))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_phone_number_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::phone_number_response(
+                "requirement-info-under-review",
+                &[],
+            )),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get(NUMBER_ID).await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap().data.status,
+        PhoneNumberStatus::RequirementInfoUnderReview
+    );
+}
+
+#[tokio::test]
+async fn get_phone_number_unauthorized() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}")))
+        .respond_with(ResponseTemplate::new(401))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get(NUMBER_ID).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 401, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_phone_number_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_phone_number_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}")))
+        .and(body_json(serde_json::json!({
+            "tags": ["support", "east"],
+            "external_pin": "1234",
+            "customer_reference": "MY REF 002"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::phone_number_response(
+                "active",
+                &["support", "east"],
+            )),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = UpdatePhoneNumberRequest::builder()
+        .tags(vec!["support".into(), "east".into()])
+        .external_pin("1234")
+        .customer_reference("MY REF 002")
+        .build();
+
+    // Act
+    let result = ctx.client.phone_numbers().update(NUMBER_ID, request).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.tags, vec!["support", "east"]);
+}
+
+#[tokio::test]
+async fn update_phone_number_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = UpdatePhoneNumberRequest::builder()
+        .connection_id("not-a-connection")
+        .build();
+
+    // Act
+    let result = ctx.client.phone_numbers().update(NUMBER_ID, request).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn delete_phone_number_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(responses::phone_number_response("deleted", &[])),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().delete(NUMBER_ID).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn delete_phone_number_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/phone_numbers/nonexistent"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().delete("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_voice_settings_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/voice")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::voice_settings_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get_voice(NUMBER_ID).await;
+
+    // Assert
+    let voice = result.unwrap().data;
+    assert_eq!(
+        voice.usage_payment_method,
+        Some(UsagePaymentMethod::PayPerMinute)
+    );
+    assert!(voice.emergency.unwrap().emergency_enabled);
+}
+
+#[tokio::test]
+async fn get_voice_settings_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers/nonexistent/voice"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get_voice("nonexistent").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_voice_settings_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/voice")))
+        .and(body_json(serde_json::json!({
+            "call_forwarding": {
+                "call_forwarding_enabled": true,
+                "forwards_to": "+13125550002",
+                "forwarding_type": "on-failure"
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::voice_settings_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .phone_numbers()
+        .update_voice(NUMBER_ID, voice_settings_request())
+        .await;
+
+    // Assert
+    let forwarding = result.unwrap().data.call_forwarding.unwrap();
+    assert_eq!(forwarding.forwards_to.as_deref(), Some("+13125550002"));
+}
+
+#[tokio::test]
+async fn update_voice_settings_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/voice")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .phone_numbers()
+        .update_voice(NUMBER_ID, voice_settings_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_messaging_settings_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/messaging")))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::messaging_settings_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.phone_numbers().get_messaging(NUMBER_ID).await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap().data.messaging_product.as_deref(),
+        Some("P2P")
+    );
+}
+
+#[tokio::test]
+async fn get_messaging_settings_not_found() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers/nonexistent/messaging"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .phone_numbers()
+        .get_messaging("nonexistent")
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn update_messaging_settings_success() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/messaging")))
+        .and(body_json(serde_json::json!({
+            "messaging_profile_id": PROFILE_ID
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(responses::messaging_settings_response()),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .phone_numbers()
+        .update_messaging(NUMBER_ID, messaging_settings_request())
+        .await;
+
+    // Assert
+    assert_eq!(
+        result.unwrap().data.messaging_profile_id.as_deref(),
+        Some(PROFILE_ID)
+    );
+}
+
+#[tokio::test]
+async fn update_messaging_settings_unprocessable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!("/phone_numbers/{NUMBER_ID}/messaging")))
+        .respond_with(ResponseTemplate::new(422))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .phone_numbers()
+        .update_messaging(NUMBER_ID, messaging_settings_request())
+        .await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 422, .. }
+    ));
+}
+
+#[test]
+fn debug_output_redacts_external_pin() {
+    // Arrange
+    let mut number: PhoneNumber =
+        serde_json::from_value(responses::phone_number_data("active", &[])).unwrap();
+    number.external_pin = Some("4821".to_string());
+    let request = UpdatePhoneNumberRequest::builder()
+        .external_pin("4821")
+        .customer_reference("MY REF 001")
+        .build();
+
+    // Act
+    let number = format!("{number:?}");
+    let request = format!("{request:?}");
+
+    // Assert
+    assert!(!number.contains("4821"));
+    assert!(number.contains("[REDACTED]"));
+    assert!(!request.contains("4821"));
+    assert!(request.contains("MY REF 001"));
+}
@@ -0,0 +1,174 @@
+mod common;
+
+use telnyx_rs::{
+    Method,
+    models::{AddressSort, ListAddressesParams, ListManagedAccountsParams, ManagedAccountSort},
+    query::{Query, ToQuery},
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path, query_param},
+};
+
+fn pairs(query: &Query) -> Vec<(&str, &str)> {
+    query
+        .pairs()
+        .iter()
+        .map(|(name, value)| (name.as_str(), value.as_str()))
+        .collect()
+}
+
+#[test]
+fn query_uses_bracket_syntax() {
+    // Act
+    let query = Query::new()
+        .page(Some(2), Some(50))
+        .filter("used_as_emergency", Some(true))
+        .filter_op("phone_number", "contains", Some("555"))
+        .sort(Some(AddressSort::CreatedAtDesc))
+        .param("include_cancelled_accounts", Some(false));
+
+    // Assert
+    assert_eq!(
+        pairs(&query),
+        [
+            ("page[number]", "2"),
+            ("page[size]", "50"),
+            ("filter[used_as_emergency]", "true"),
+            ("filter[phone_number][contains]", "555"),
+            ("sort", "-created_at"),
+            ("include_cancelled_accounts", "false"),
+        ]
+    );
+}
+
+#[test]
+fn missing_values_are_skipped() {
+    // Act
+    let query = Query::new()
+        .page(None, Some(10))
+        .filter::<&str>("email", None)
+        .sort::<AddressSort>(None);
+
+    // Assert
+    assert_eq!(pairs(&query), [("page[size]", "10")]);
+}
+
+#[test]
+fn query_string_is_url_encoded() {
+    // Act
+    let query = Query::new()
+        .filter_op("street_address", "contains", Some("311 W Superior"))
+        .sort(Some("-created_at"));
+
+    // Assert
+    assert_eq!(
+        query.to_query_string(),
+        "filter%5Bstreet_address%5D%5Bcontains%5D=311+W+Superior&sort=-created_at"
+    );
+}
+
+#[test]
+fn queries_can_be_combined() {
+    // Act
+    let query = Query::new()
+        .page(Some(1), None)
+        .extend(Query::new().filter("country_code", Some("US")));
+
+    // Assert
+    assert_eq!(
+        pairs(&query),
+        [("page[number]", "1"), ("filter[country_code]", "US")]
+    );
+}
+
+#[test]
+fn default_params_encode_to_an_empty_query() {
+    // Assert
+    assert!(ListAddressesParams::default().to_query().is_empty());
+    assert!(ListManagedAccountsParams::default().to_query().is_empty());
+}
+
+#[test]
+fn address_params_are_encoded() {
+    // Arrange
+    let params = ListAddressesParams::builder()
+        .page_number(3)
+        .page_size(25)
+        .customer_reference_eq("ref-1")
+        .street_address_contains("Superior")
+        .address_book_eq(true)
+        .used_as_emergency(false)
+        .sort(AddressSort::StreetAddress)
+        .build();
+
+    // Act
+    let query = params.to_query();
+
+    // Assert
+    assert_eq!(
+        pairs(&query),
+        [
+            ("page[number]", "3"),
+            ("page[size]", "25"),
+            ("filter[customer_reference][eq]", "ref-1"),
+            ("filter[street_address][contains]", "Superior"),
+            ("filter[address_book][eq]", "true"),
+            ("filter[used_as_emergency]", "false"),
+            ("sort", "street_address"),
+        ]
+    );
+}
+
+#[test]
+fn managed_account_params_are_encoded() {
+    // Arrange
+    let params = ListManagedAccountsParams::builder()
+        .email_contains("@acme.example")
+        .organization_name_eq("Acme Inc")
+        .sort(ManagedAccountSort::EmailDesc)
+        .build();
+
+    // Act
+    let query = params.to_query();
+
+    // Assert
+    assert_eq!(
+        pairs(&query),
+        [
+            ("filter[email][contains]", "@acme.example"),
+            ("filter[organization_name][eq]", "Acme Inc"),
+            ("sort", "-email"),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn query_can_be_sent_with_raw_requests() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/phone_numbers"))
+        .and(query_param("filter[phone_number][contains]", "555"))
+        .and(query_param("page[size]", "20"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": [] })))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let query = Query::new()
+        .filter_op("phone_number", "contains", Some("555"))
+        .page(None, Some(20));
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::GET, "/phone_numbers")
+        .query(&query)
+        .send::<serde_json::Value>()
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+}
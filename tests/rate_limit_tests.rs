@@ -0,0 +1,180 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use telnyx_rs::{Quota, RateLimitPolicy, RetryPolicy, TelnyxClient, TelnyxError};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+async fn mount_delete(ctx: &common::TestContext) {
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&ctx.server)
+        .await;
+}
+
+#[tokio::test]
+async fn global_quota_queues_concurrent_requests() {
+    // Arrange
+    let policy = RateLimitPolicy::new().global(Quota::new(1, Duration::from_millis(100)));
+    let ctx = common::setup_with(|builder| builder.rate_limit(policy)).await;
+    mount_delete(&ctx).await;
+
+    // Act
+    let started = Instant::now();
+    let addresses = ctx.client.addresses();
+    let results = join_all((0..3).map(|_| addresses.delete("123"))).await;
+
+    // Assert
+    assert!(results.iter().all(Result::is_ok));
+    assert!(started.elapsed() >= Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn group_quota_limits_its_endpoint_group() {
+    // Arrange
+    let policy =
+        RateLimitPolicy::new().group("addresses", Quota::new(2, Duration::from_millis(200)));
+    let ctx = common::setup_with(|builder| builder.rate_limit(policy)).await;
+    mount_delete(&ctx).await;
+
+    // Act
+    let started = Instant::now();
+    for _ in 0..3 {
+        ctx.client.addresses().delete("123").await.unwrap();
+    }
+
+    // Assert
+    assert!(started.elapsed() >= Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn exhausted_rate_limit_headers_pause_the_group() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .rate_limit(RateLimitPolicy::new())
+            .retry_policy(RetryPolicy::disabled())
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-ratelimit-remaining", "0")
+                .insert_header("x-ratelimit-reset", "1"),
+        )
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    mount_delete(&ctx).await;
+
+    // Act
+    ctx.client.addresses().delete("123").await.unwrap();
+    let started = Instant::now();
+    ctx.client.addresses().delete("123").await.unwrap();
+
+    // Assert
+    assert!(started.elapsed() >= Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn huge_rate_limit_reset_is_capped_at_max_delay() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder.rate_limit(RateLimitPolicy::new()).retry_policy(
+            RetryPolicy::builder()
+                .max_attempts(1)
+                .max_delay(Duration::from_millis(200))
+                .build(),
+        )
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("x-ratelimit-remaining", "0")
+                .insert_header("x-ratelimit-reset", u64::MAX.to_string()),
+        )
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    mount_delete(&ctx).await;
+
+    // Act
+    ctx.client.addresses().delete("123").await.unwrap();
+    let started = Instant::now();
+    ctx.client.addresses().delete("123").await.unwrap();
+
+    // Assert
+    let elapsed = started.elapsed();
+    assert!(elapsed >= Duration::from_millis(150));
+    assert!(elapsed < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn quota_with_huge_period_waits_without_panicking() {
+    // Arrange
+    let policy = RateLimitPolicy::new().global(Quota::new(1, Duration::MAX));
+    let ctx = common::setup_with(|builder| builder.rate_limit(policy)).await;
+    mount_delete(&ctx).await;
+
+    // Act
+    ctx.client.addresses().delete("123").await.unwrap();
+    let second = tokio::time::timeout(
+        Duration::from_millis(100),
+        ctx.client.addresses().delete("123"),
+    )
+    .await;
+
+    // Assert
+    assert!(second.is_err());
+}
+
+#[tokio::test]
+async fn retry_after_on_429_pauses_the_group() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder
+            .rate_limit(RateLimitPolicy::new())
+            .retry_policy(RetryPolicy::disabled())
+    })
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    mount_delete(&ctx).await;
+
+    // Act
+    let first = ctx.client.addresses().delete("123").await;
+    let started = Instant::now();
+    let second = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(first.unwrap_err().is_rate_limited());
+    assert!(second.is_ok());
+    assert!(started.elapsed() >= Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn zero_quota_is_a_config_error() {
+    // Act
+    let result = TelnyxClient::builder()
+        .api_key("test-api-key")
+        .rate_limit(RateLimitPolicy::new().global(Quota::per_second(0)))
+        .build();
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
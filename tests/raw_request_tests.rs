@@ -0,0 +1,170 @@
+mod common;
+
+use serde::Deserialize;
+use telnyx_rs::{Method, RequestOptions, TelnyxError, models::ApiResponse};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, body_json, header, method, path, query_param},
+};
+
+#[derive(Debug, Deserialize)]
+struct Balance {
+    balance: String,
+    currency: String,
+}
+
+#[tokio::test]
+async fn get_with_query_deserializes_response() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/balance"))
+        .and(bearer_token("test-api-key"))
+        .and(query_param("filter[currency]", "USD"))
+        .and(query_param("page[size]", "10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": { "balance": "300.00", "currency": "USD" }
+        })))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result: Result<ApiResponse<Balance>, _> = ctx
+        .client
+        .request(Method::GET, "balance")
+        .query(&[("filter[currency]", "USD")])
+        .query(&[("page[size]", 10)])
+        .send()
+        .await;
+
+    // Assert
+    let balance = result.unwrap().data;
+    assert_eq!(balance.balance, "300.00");
+    assert_eq!(balance.currency, "USD");
+}
+
+#[tokio::test]
+async fn post_sends_json_body_and_options() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(header("content-type", "application/json"))
+        .and(header("Idempotency-Key", "message-1"))
+        .and(body_json(serde_json::json!({ "to": "+13125550001" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": { "id": "1" } })),
+        )
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result: Result<serde_json::Value, _> = ctx
+        .client
+        .request(Method::POST, "/messages")
+        .json(&serde_json::json!({ "to": "+13125550001" }))
+        .options(RequestOptions::new().idempotency_key("message-1"))
+        .send()
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap()["data"]["id"], "1");
+}
+
+#[tokio::test]
+async fn error_responses_are_parsed() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/unknown"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "errors": [{ "code": "10005", "title": "Resource not found" }]
+        })))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::GET, "/unknown")
+        .send::<serde_json::Value>()
+        .await;
+
+    // Assert
+    let error = result.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.api_errors()[0].code, "10005");
+}
+
+#[tokio::test]
+async fn send_empty_returns_response_meta() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(204).insert_header("x-request-id", "req-1"))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::DELETE, "/addresses/123")
+        .send_empty()
+        .await;
+
+    // Assert
+    let meta = result.unwrap();
+    assert_eq!(meta.status.as_u16(), 204);
+    assert_eq!(meta.request_id.as_deref(), Some("req-1"));
+}
+
+#[tokio::test]
+async fn invalid_query_is_rejected_before_sending() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::GET, "/balance")
+        .query(&serde_json::json!({ "nested": { "key": "value" } }))
+        .send::<serde_json::Value>()
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::InvalidRequest(_))));
+}
+
+#[tokio::test]
+async fn idempotency_key_on_non_post_is_rejected() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/balance"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .request(Method::PATCH, "/balance")
+        .json(&serde_json::json!({ "currency": "USD" }))
+        .options(RequestOptions::new().idempotency_key("order-1234"))
+        .send_empty()
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::InvalidRequest(_))));
+}
@@ -0,0 +1,194 @@
+mod common;
+
+use std::time::Duration;
+
+use telnyx_rs::{RequestOptions, RetryPolicy, TelnyxError, models::CreateAddressRequest};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, header, method, path},
+};
+
+fn address_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": 123,
+            "record_type": "address",
+            "street_address": "311 W Superior St",
+            "locality": "Chicago",
+            "country_code": "US",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+}
+
+#[tokio::test]
+async fn options_timeout_overrides_client_timeout() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(RetryPolicy::disabled())).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(address_response())
+                .set_delay(Duration::from_millis(500)),
+        )
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().timeout(Duration::from_millis(50)))
+        .get("123")
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Timeout(_))));
+}
+
+#[tokio::test]
+async fn options_add_headers() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("test-api-key"))
+        .and(header("x-correlation-id", "batch-42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let options = RequestOptions::new().header("x-correlation-id", "batch-42");
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(options)
+        .get("123")
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, 123);
+}
+
+#[tokio::test]
+async fn options_retry_policy_overrides_client_policy() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().retry_policy(RetryPolicy::disabled()))
+        .delete("123")
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap_err().status(), Some(503));
+}
+
+#[tokio::test]
+async fn options_idempotency_key_makes_post_retryable() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header("Idempotency-Key", "order-1234"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .and(header("Idempotency-Key", "order-1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let options = RequestOptions::new()
+        .idempotency_key("order-1234")
+        .retry_policy(
+            RetryPolicy::builder()
+                .base_delay(Duration::from_millis(1))
+                .build(),
+        );
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St")
+        .locality("Chicago")
+        .country_code("US")
+        .build();
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(options)
+        .create(request)
+        .await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, 123);
+}
+
+#[tokio::test]
+async fn invalid_option_headers_are_rejected() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .with_options(RequestOptions::new().header("bad header", "value"))
+        .get("123")
+        .await;
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::InvalidRequest(_))));
+}
+
+#[tokio::test]
+async fn reserved_option_headers_are_rejected() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .expect(0)
+        .mount(&ctx.server)
+        .await;
+
+    for name in ["Authorization", "idempotency-key", "Content-Type"] {
+        // Act
+        let result = ctx
+            .client
+            .addresses()
+            .with_options(RequestOptions::new().header(name, "value"))
+            .get("123")
+            .await;
+
+        // Assert
+        assert!(
+            matches!(result, Err(TelnyxError::InvalidRequest(_))),
+            "{name} was not rejected"
+        );
+    }
+}
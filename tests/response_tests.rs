@@ -0,0 +1,125 @@
+mod common;
+
+use telnyx_rs::RetryPolicy;
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn address_response() -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": 123,
+            "record_type": "address",
+            "street_address": "311 W Superior St",
+            "locality": "Chicago",
+            "country_code": "US",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+}
+
+#[tokio::test]
+async fn response_meta_is_attached() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(address_response())
+                .insert_header("x-request-id", "req-abc")
+                .insert_header("x-ratelimit-limit", "100")
+                .insert_header("x-ratelimit-remaining", "99")
+                .insert_header("x-ratelimit-reset", "30"),
+        )
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().get("123").await;
+
+    // Assert
+    let meta = result.unwrap().response_meta.unwrap();
+    assert_eq!(meta.status.as_u16(), 200);
+    assert_eq!(meta.request_id.as_deref(), Some("req-abc"));
+    assert_eq!(meta.header("x-ratelimit-limit"), Some("100"));
+    assert_eq!(meta.attempts, 1);
+    let rate_limit = meta.rate_limit().unwrap();
+    assert_eq!(rate_limit.limit, Some(100));
+    assert_eq!(rate_limit.remaining, Some(99));
+    assert_eq!(rate_limit.reset, Some(30));
+}
+
+#[tokio::test]
+async fn list_response_meta_is_attached() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!({ "data": [] }))
+                .insert_header("x-request-id", "req-list"),
+        )
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    let meta = result.unwrap().response_meta.unwrap();
+    assert_eq!(meta.request_id.as_deref(), Some("req-list"));
+    assert!(meta.rate_limit().is_none());
+}
+
+#[tokio::test]
+async fn response_meta_counts_attempts() {
+    // Arrange
+    let policy = RetryPolicy::builder()
+        .base_delay(std::time::Duration::from_millis(1))
+        .build();
+    let ctx = common::setup_with(|builder| builder.retry_policy(policy)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response()))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().get("123").await;
+
+    // Assert
+    assert_eq!(result.unwrap().response_meta.unwrap().attempts, 2);
+}
+
+#[tokio::test]
+async fn api_error_carries_request_id() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(404).insert_header("x-request-id", "req-404"))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    let error = result.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.request_id(), Some("req-404"));
+}
@@ -0,0 +1,285 @@
+mod common;
+
+use std::{
+    io::Read,
+    net::TcpListener,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+    time::{Duration, Instant},
+};
+
+use futures::future::BoxFuture;
+use telnyx_rs::{
+    HttpTransport, ReqwestTransport, RetryPolicy, TelnyxError, TransportRequest, TransportResponse,
+    Url, models::CreateAddressRequest,
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+fn fast_retries(max_attempts: u32) -> RetryPolicy {
+    RetryPolicy::builder()
+        .max_attempts(max_attempts)
+        .base_delay(Duration::from_millis(1))
+        .max_delay(Duration::from_millis(10))
+        .jitter(false)
+        .build()
+}
+
+fn empty_list() -> serde_json::Value {
+    serde_json::json!({ "data": [] })
+}
+
+#[tokio::test]
+async fn get_retries_server_errors_until_success() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries(3))).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(502))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_list()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+/// Transport whose first request goes to a server that hangs up without
+/// responding, so it fails with a dropped connection
+struct HangUpOnceTransport {
+    inner: ReqwestTransport,
+    hang_up_url: Url,
+    calls: Arc<AtomicU32>,
+}
+
+impl HangUpOnceTransport {
+    fn new(calls: Arc<AtomicU32>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let hang_up_url =
+            Url::parse(&format!("http://{}/", listener.local_addr().unwrap())).unwrap();
+        std::thread::spawn(move || {
+            if let Ok((mut stream, _)) = listener.accept() {
+                let _ = stream.read(&mut [0; 1024]);
+            }
+        });
+
+        Self {
+            inner: ReqwestTransport::new(reqwest::Client::new()),
+            hang_up_url,
+            calls,
+        }
+    }
+}
+
+impl HttpTransport for HangUpOnceTransport {
+    fn send(
+        &self,
+        mut request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, TelnyxError>> {
+        if self.calls.fetch_add(1, Ordering::SeqCst) == 0 {
+            request.url = self.hang_up_url.clone();
+        }
+        self.inner.send(request)
+    }
+}
+
+#[tokio::test]
+async fn get_retries_dropped_connections() {
+    // Arrange
+    let calls = Arc::new(AtomicU32::new(0));
+    let transport = HangUpOnceTransport::new(calls.clone());
+    let ctx =
+        common::setup_with(|builder| builder.retry_policy(fast_retries(3)).transport(transport))
+            .await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_list()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn get_gives_up_after_max_attempts() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries(3))).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(3)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 503, .. }
+    ));
+}
+
+#[tokio::test]
+async fn get_retries_rate_limited_responses() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries(2))).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "0"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_list()))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn retry_after_header_is_honored() {
+    // Arrange
+    let policy = RetryPolicy::builder()
+        .max_attempts(2)
+        .base_delay(Duration::from_millis(1))
+        .build();
+    let ctx = common::setup_with(|builder| builder.retry_policy(policy)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(429).insert_header("Retry-After", "1"))
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(empty_list()))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let started = Instant::now();
+    let result = ctx.client.addresses().list(None).await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert!(started.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries(3))).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(404))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().get("123").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 404, .. }
+    ));
+}
+
+#[tokio::test]
+async fn post_without_idempotency_key_is_not_retried() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(fast_retries(3))).await;
+
+    Mock::given(method("POST"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St")
+        .locality("Chicago")
+        .country_code("US")
+        .build();
+
+    // Act
+    let result = ctx.client.addresses().create(request).await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 503, .. }
+    ));
+}
+
+#[tokio::test]
+async fn disabled_policy_sends_once() {
+    // Arrange
+    let ctx = common::setup_with(|builder| builder.retry_policy(RetryPolicy::disabled())).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/addresses/123"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().delete("123").await;
+
+    // Assert
+    assert!(matches!(
+        result.unwrap_err(),
+        TelnyxError::Api { status: 500, .. }
+    ));
+}
+
+#[tokio::test]
+async fn zero_attempts_is_a_config_error() {
+    // Act
+    let result = telnyx_rs::TelnyxClient::builder()
+        .api_key("test-api-key")
+        .retry_policy(fast_retries(0))
+        .build();
+
+    // Assert
+    assert!(matches!(result, Err(TelnyxError::Config(_))));
+}
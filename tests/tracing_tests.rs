@@ -0,0 +1,196 @@
+#![cfg(feature = "tracing")]
+
+mod common;
+
+use std::{
+    io::Write,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use telnyx_rs::RetryPolicy;
+use tracing::instrument::WithSubscriber;
+use tracing_subscriber::fmt::{MakeWriter, format::FmtSpan};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{method, path},
+};
+
+#[derive(Clone, Default)]
+struct CapturedLogs(Arc<Mutex<Vec<u8>>>);
+
+impl CapturedLogs {
+    fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock().unwrap()).into_owned()
+    }
+}
+
+impl Write for CapturedLogs {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<'a> MakeWriter<'a> for CapturedLogs {
+    type Writer = CapturedLogs;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        self.clone()
+    }
+}
+
+#[tokio::test]
+async fn request_span_records_request_without_secrets() {
+    // Arrange
+    let ctx = common::setup().await;
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(logs.clone())
+        .finish();
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_string("secret-body")
+                .insert_header("x-request-id", "req-traced"),
+        )
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .get("123")
+        .with_subscriber(subscriber)
+        .await;
+
+    // Assert
+    assert!(result.is_err());
+    let output = logs.contents();
+    assert!(output.contains("telnyx.request"));
+    assert!(output.contains("http.method=GET"));
+    assert!(output.contains("http.path=/addresses/{id}"));
+    assert!(output.contains("http.status=404"));
+    assert!(output.contains("attempt=1"));
+    assert!(output.contains("request_id=\"req-traced\""));
+    assert!(output.contains("WARN"));
+    assert!(output.contains("request returned an error status"));
+    assert!(!output.contains("secret-body"));
+    assert!(!output.contains("test-api-key"));
+}
+
+#[tokio::test]
+async fn request_span_duration_includes_retry_backoff() {
+    // Arrange
+    let ctx = common::setup_with(|builder| {
+        builder.retry_policy(
+            RetryPolicy::builder()
+                .base_delay(Duration::from_millis(200))
+                .jitter(false)
+                .build(),
+        )
+    })
+    .await;
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::INFO)
+        .with_span_events(FmtSpan::CLOSE)
+        .with_ansi(false)
+        .with_writer(logs.clone())
+        .finish();
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&ctx.server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({ "data": [] })))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list(None)
+        .with_subscriber(subscriber)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+    let output = logs.contents();
+    assert!(output.contains("attempt=2"));
+    let field = |name: &str| -> u64 {
+        let start = output.find(&format!("{name}=")).unwrap() + name.len() + 1;
+        output[start..]
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap()
+    };
+    assert!(field("duration_ms") >= 200);
+    assert!(field("attempt_latency_ms") < 200);
+}
+
+#[tokio::test]
+async fn response_bodies_are_not_logged_at_trace() {
+    // Arrange
+    let ctx = common::setup().await;
+    let logs = CapturedLogs::default();
+    let subscriber = tracing_subscriber::fmt()
+        .with_max_level(tracing::Level::TRACE)
+        .with_ansi(false)
+        .with_writer(logs.clone())
+        .finish();
+
+    Mock::given(method("GET"))
+        .and(path("/addresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": [],
+            "note": "secret-body"
+        })))
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx
+        .client
+        .addresses()
+        .list(None)
+        .with_subscriber(subscriber)
+        .await;
+
+    // Assert
+    assert!(result.is_ok());
+    let output = logs.contents();
+    assert!(output.contains("request completed"));
+    assert!(output.contains("body_bytes="));
+    assert!(!output.contains("secret-body"));
+}
+
+#[tokio::test]
+async fn client_debug_redacts_api_key() {
+    // Arrange
+    let ctx = common::setup().await;
+
+    // Act
+    let debug = format!("{:?}", ctx.client);
+
+    // Assert
+    assert!(debug.contains("[REDACTED]"));
+    assert!(!debug.contains("test-api-key"));
+}
@@ -0,0 +1,195 @@
+mod common;
+
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use futures::future::BoxFuture;
+use telnyx_rs::{
+    HttpTransport, Method, RetryPolicy, StatusCode, TelnyxClient, TelnyxError, TransportRequest,
+    TransportResponse, models::CreateAddressRequest,
+};
+use wiremock::{
+    Mock, ResponseTemplate,
+    matchers::{bearer_token, method, path},
+};
+
+/// In-memory transport replaying canned responses and recording requests
+#[derive(Clone, Default)]
+struct FakeTransport {
+    responses: Arc<Mutex<VecDeque<TransportResponse>>>,
+    requests: Arc<Mutex<Vec<TransportRequest>>>,
+}
+
+impl FakeTransport {
+    fn respond(self, status: u16, body: serde_json::Value) -> Self {
+        self.responses.lock().unwrap().push_back(TransportResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            headers: Default::default(),
+            body: serde_json::to_vec(&body).unwrap().into(),
+        });
+        self
+    }
+
+    fn requests(&self) -> Vec<TransportRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+impl HttpTransport for FakeTransport {
+    fn send(
+        &self,
+        request: TransportRequest,
+    ) -> BoxFuture<'_, Result<TransportResponse, TelnyxError>> {
+        self.requests.lock().unwrap().push(request);
+        let response = self
+            .responses
+            .lock()
+            .unwrap()
+            .pop_front()
+            .unwrap_or_default();
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+fn address_response(id: i64) -> serde_json::Value {
+    serde_json::json!({
+        "data": {
+            "id": id,
+            "record_type": "address",
+            "street_address": "311 W Superior St",
+            "locality": "Chicago",
+            "country_code": "US",
+            "created_at": "2024-01-01T00:00:00Z",
+            "updated_at": "2024-01-01T00:00:00Z"
+        }
+    })
+}
+
+fn client_with(transport: FakeTransport) -> TelnyxClient {
+    TelnyxClient::builder()
+        .api_key("test-api-key")
+        .base_url("https://telnyx.test/v2")
+        .retry_policy(
+            RetryPolicy::builder()
+                .base_delay(Duration::from_millis(1))
+                .build(),
+        )
+        .transport(transport)
+        .build()
+        .expect("Failed to build test client")
+}
+
+#[tokio::test]
+async fn fake_transport_receives_prepared_request() {
+    // Arrange
+    let transport = FakeTransport::default().respond(200, address_response(123));
+    let client = client_with(transport.clone());
+
+    let request = CreateAddressRequest::builder()
+        .street_address("311 W Superior St")
+        .locality("Chicago")
+        .country_code("US")
+        .build();
+
+    // Act
+    let result = client.addresses().create(request).await;
+
+    // Assert
+    assert_eq!(result.unwrap().data.id, 123);
+    let requests = transport.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, Method::POST);
+    assert_eq!(requests[0].url.as_str(), "https://telnyx.test/v2/addresses");
+    assert_eq!(
+        requests[0].headers["authorization"].to_str().unwrap(),
+        "Bearer test-api-key"
+    );
+    assert_eq!(
+        requests[0].headers["content-type"].to_str().unwrap(),
+        "application/json"
+    );
+    let body: serde_json::Value =
+        serde_json::from_slice(requests[0].body.as_ref().unwrap()).unwrap();
+    assert_eq!(body["street_address"], "311 W Superior St");
+}
+
+#[tokio::test]
+async fn fake_transport_is_retried() {
+    // Arrange
+    let transport = FakeTransport::default()
+        .respond(503, serde_json::json!({}))
+        .respond(200, address_response(123));
+    let client = client_with(transport.clone());
+
+    // Act
+    let result = client.addresses().get("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+    assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn fake_transport_error_response() {
+    // Arrange
+    let transport = FakeTransport::default().respond(
+        404,
+        serde_json::json!({ "errors": [{ "code": "10005", "title": "Resource not found" }] }),
+    );
+    let client = client_with(transport);
+
+    // Act
+    let result = client.addresses().get("123").await;
+
+    // Assert
+    let error = result.unwrap_err();
+    assert!(error.is_not_found());
+    assert_eq!(error.api_errors()[0].code, "10005");
+}
+
+#[tokio::test]
+async fn preconfigured_reqwest_client_is_used() {
+    // Arrange
+    let http_client = reqwest::Client::builder()
+        .user_agent("custom-agent")
+        .build()
+        .unwrap();
+    let ctx = common::setup_with(|builder| builder.http_client(http_client)).await;
+
+    Mock::given(method("GET"))
+        .and(path("/addresses/123"))
+        .and(bearer_token("test-api-key"))
+        .and(wiremock::matchers::header("user-agent", "custom-agent"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(address_response(123)))
+        .expect(1)
+        .mount(&ctx.server)
+        .await;
+
+    // Act
+    let result = ctx.client.addresses().get("123").await;
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn conflicting_transport_options_are_rejected() {
+    // Act
+    let both = TelnyxClient::builder()
+        .api_key("test-api-key")
+        .http_client(reqwest::Client::new())
+        .transport(FakeTransport::default())
+        .build();
+    let with_timeout = TelnyxClient::builder()
+        .api_key("test-api-key")
+        .timeout(Duration::from_secs(5))
+        .transport(FakeTransport::default())
+        .build();
+
+    // Assert
+    assert!(matches!(both, Err(TelnyxError::Config(_))));
+    assert!(matches!(with_timeout, Err(TelnyxError::Config(_))));
+}